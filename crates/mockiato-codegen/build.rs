@@ -1,7 +1,16 @@
-use rustc_version::{version_meta, Channel};
+use rustc_version::{version_meta, Channel, Version};
 
 fn main() {
-    if let Channel::Nightly = version_meta().unwrap().channel {
+    println!("cargo:rustc-check-cfg=cfg(rustc_is_nightly)");
+    println!("cargo:rustc-check-cfg=cfg(rustc_supports_async_fn_in_trait)");
+
+    let version_meta = version_meta().unwrap();
+
+    if let Channel::Nightly = version_meta.channel {
         println!("cargo:rustc-cfg=rustc_is_nightly");
     }
+
+    if version_meta.semver >= Version::new(1, 75, 0) {
+        println!("cargo:rustc-cfg=rustc_supports_async_fn_in_trait");
+    }
 }
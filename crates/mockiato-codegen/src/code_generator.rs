@@ -1,3 +1,4 @@
+use crate::parse::mockable_attr::{ConstantValue, SupertraitMock};
 use crate::parse::trait_decl::TraitDecl;
 use proc_macro2::TokenStream;
 use std::fmt::Debug;
@@ -8,6 +9,10 @@ pub(crate) struct GenerateOptions {
     pub(crate) custom_struct_ident: Option<Ident>,
     pub(crate) force_static_lifetimes: bool,
     pub(crate) custom_trait_path: Option<Path>,
+    pub(crate) constants: Vec<ConstantValue>,
+    pub(crate) supertrait_mocks: Vec<SupertraitMock>,
+    pub(crate) sync: bool,
+    pub(crate) nice: bool,
 }
 
 #[cfg_attr(test, mockiato::mockable)]
@@ -1,12 +1,26 @@
 use crate::code_generator::{self, CodeGenerator};
 use crate::code_generator_impl::arguments::generate_arguments;
+use crate::code_generator_impl::associated_types::{
+    generics_with_associated_types, resolve_associated_types,
+};
 use crate::code_generator_impl::constant::{arguments_ident, arguments_matcher_ident};
 use crate::code_generator_impl::constant::{
     mock_lifetime, mock_lifetime_as_generic_param, mock_struct_ident, mod_ident,
 };
+use crate::code_generator_impl::default_impls::{
+    generate_default_impls_trait, generate_default_impls_trait_impl,
+};
 use crate::code_generator_impl::drop_impl::generate_drop_impl;
-use crate::code_generator_impl::generics::get_matching_generics_for_method_inputs;
+use crate::code_generator_impl::generics::{
+    combine_trait_and_method_generics, get_matching_generics_for_method_inputs,
+};
 use crate::code_generator_impl::mock_struct::generate_mock_struct;
+use crate::code_generator_impl::static_methods::{
+    generate_static_guard, generate_static_methods_struct,
+};
+use crate::code_generator_impl::supertraits::{
+    generate_forward_to_mock_macro, generate_supertrait_impls,
+};
 use crate::code_generator_impl::trait_impl::generate_trait_impl;
 use crate::code_generator_impl::visibility::raise_visibility_by_one_level;
 use crate::parse::method_decl::MethodDecl;
@@ -20,19 +34,32 @@ pub(crate) use crate::code_generator_impl::arguments_matcher::*;
 
 mod arguments;
 mod arguments_matcher;
+mod associated_types;
 mod bound_lifetimes;
+mod checkpoint;
 mod constant;
 mod debug_impl;
+mod default_impls;
 mod drop_impl;
 mod generics;
 mod lifetime_rewriter;
 mod mock_struct;
+mod recorded_calls;
+mod static_methods;
+mod supertraits;
 mod trait_impl;
 mod util;
+mod verify;
 mod visibility;
 
 pub(crate) trait ArgumentsMatcherGenerator: Debug {
-    fn generate(&self, method: &MethodDeclMetadata, visibility: &Visibility) -> TokenStream;
+    /// `sync` specifies whether the argument matchers need to be `Send` and `Sync`.
+    fn generate(
+        &self,
+        method: &MethodDeclMetadata,
+        visibility: &Visibility,
+        sync: bool,
+    ) -> TokenStream;
 }
 
 #[derive(Debug)]
@@ -54,6 +81,8 @@ impl CodeGenerator for CodeGeneratorImpl {
         trait_decl: &TraitDecl,
         options: code_generator::GenerateOptions,
     ) -> TokenStream {
+        let unresolved_trait_decl = trait_decl;
+        let trait_decl = &resolve_associated_types(trait_decl);
         let mock_struct_ident = options
             .custom_struct_ident
             .unwrap_or_else(|| mock_struct_ident(trait_decl));
@@ -81,19 +110,27 @@ impl CodeGenerator for CodeGeneratorImpl {
             generics: generics_for_trait_decl(trait_decl, static_lifetime_restriction),
             methods,
             trait_path,
+            sync: options.sync,
+            nice: options.nice,
         };
 
-        let mock_struct = generate_mock_struct(trait_decl, &parameters);
+        let mock_struct = generate_mock_struct(trait_decl, &parameters, &options.supertrait_mocks);
 
-        let trait_impl = generate_trait_impl(trait_decl, &parameters);
+        let trait_impl = generate_trait_impl(trait_decl, &parameters, &options.constants);
 
         let arguments: TokenStream = parameters
             .methods
             .iter()
-            .map(|method| self.generate_argument_structs(method, trait_decl))
+            .map(|method| self.generate_argument_structs(method, trait_decl, &parameters))
             .collect();
 
         let drop_impl = generate_drop_impl(trait_decl, &parameters);
+        let static_guard = generate_static_guard(trait_decl, &parameters);
+        let static_methods_struct = generate_static_methods_struct(trait_decl, &parameters);
+        let supertrait_impls = generate_supertrait_impls(&parameters, &options.supertrait_mocks);
+        let forward_to_mock_macro = generate_forward_to_mock_macro(trait_decl, &parameters);
+        let default_impls_trait = generate_default_impls_trait(unresolved_trait_decl, &parameters);
+        let default_impls_trait_impl = generate_default_impls_trait_impl(trait_decl, &parameters);
         let mod_ident = &parameters.mod_ident;
 
         // The sub-mod is used to hide implementation details from the user
@@ -105,10 +142,22 @@ impl CodeGenerator for CodeGeneratorImpl {
 
             #drop_impl
 
+            #static_guard
+
+            #supertrait_impls
+
+            #forward_to_mock_macro
+
+            #default_impls_trait_impl
+
             mod #mod_ident {
                 use super::*;
 
                 #arguments
+
+                #static_methods_struct
+
+                #default_impls_trait
             }
         }
     }
@@ -119,12 +168,13 @@ impl CodeGeneratorImpl {
         &self,
         method: &MethodDeclMetadata,
         trait_decl: &TraitDecl,
+        parameters: &GenerateMockParameters,
     ) -> proc_macro2::TokenStream {
         let visibility = raise_visibility_by_one_level(&trait_decl.visibility);
         let arguments = generate_arguments(method, &visibility);
-        let arguments_matcher = self
-            .arguments_matcher_generator
-            .generate(method, &visibility);
+        let arguments_matcher =
+            self.arguments_matcher_generator
+                .generate(method, &visibility, parameters.sync);
 
         quote! {
             #arguments
@@ -140,8 +190,13 @@ pub(crate) struct GenerateMockParameters {
     pub(crate) generics: Generics,
     pub(crate) trait_path: Path,
     pub(crate) methods: Vec<MethodDeclMetadata>,
+    /// Whether the mock is `Send` and `Sync`
+    pub(crate) sync: bool,
+    /// Whether unexpected calls return default values instead of panicking
+    pub(crate) nice: bool,
 }
 
+#[derive(Clone)]
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
 pub(crate) struct MethodDeclMetadata {
     pub(crate) method_decl: MethodDecl,
@@ -159,8 +214,13 @@ fn map_method_decl_to_method_decl_metadata(
     method_decl: MethodDecl,
     trait_decl: &TraitDecl,
 ) -> MethodDeclMetadata {
-    let generics =
-        get_matching_generics_for_method_inputs(&method_decl.inputs, &trait_decl.generics);
+    let generics = get_matching_generics_for_method_inputs(
+        &method_decl.inputs,
+        &combine_trait_and_method_generics(
+            &generics_with_associated_types(trait_decl),
+            &method_decl.generics,
+        ),
+    );
     let arguments_struct_ident = arguments_ident(&method_decl.ident);
     let arguments_matcher_struct_ident = arguments_matcher_ident(&method_decl.ident);
     let return_type = return_type(&method_decl);
@@ -178,9 +238,44 @@ fn generics_for_trait_decl(
     trait_decl: &TraitDecl,
     static_lifetime_restriction: Option<WherePredicate>,
 ) -> Generics {
-    let mut generics = trait_decl.generics.clone();
+    let mut generics = generics_with_associated_types(trait_decl);
     generics.params.push(mock_lifetime_as_generic_param());
 
+    let mock_lifetime = mock_lifetime();
+
+    // Values borrowed for the trait's lifetimes are stored in the mock.
+    for lifetime_def in trait_decl.generics.lifetimes() {
+        let lifetime = &lifetime_def.lifetime;
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#lifetime: #mock_lifetime));
+    }
+
+    // The expectations of generic methods are type-erased using `Any`,
+    // which requires all types and lifetimes they contain to be `'static`.
+    if trait_decl.methods.iter().any(has_type_params) {
+        for lifetime_def in trait_decl.generics.lifetimes() {
+            let lifetime = &lifetime_def.lifetime;
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#lifetime: 'static));
+        }
+
+        let type_params: Vec<_> = generics
+            .type_params()
+            .map(|type_param| type_param.ident.clone())
+            .collect();
+
+        for ident in type_params {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ident: 'static));
+        }
+    }
+
     if let Some(static_lifetime_restriction) = static_lifetime_restriction {
         generics
             .make_where_clause()
@@ -191,6 +286,10 @@ fn generics_for_trait_decl(
     generics
 }
 
+fn has_type_params(method_decl: &MethodDecl) -> bool {
+    method_decl.generics.type_params().next().is_some()
+}
+
 fn get_static_lifetime_restriction() -> WherePredicate {
     let mock_lifetime = mock_lifetime();
     parse_quote!(#mock_lifetime: 'static)
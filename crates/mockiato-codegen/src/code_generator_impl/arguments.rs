@@ -22,7 +22,10 @@ pub(crate) fn generate_arguments(
 
     let mut lifetime_rewriter =
         LifetimeRewriter::new(UniformLifetimeGenerator::new(arguments_lifetime()));
-    let arguments_fields = generate_arguments_fields(&mut lifetime_rewriter, &method_decl.inputs);
+    let arguments_fields =
+        generate_arguments_fields(&mut lifetime_rewriter, &method_decl.inputs, visibility);
+
+    let trait_lifetimes: Vec<_> = generics.lifetimes().map(|def| &def.lifetime).collect();
 
     let mut generics = generics.clone();
     generics.params.push(arguments_lifetime_as_generic_param());
@@ -40,7 +43,7 @@ pub(crate) fn generate_arguments(
         #[doc(hidden)]
         #visibility struct #arguments_struct_ident #ty_generics #where_clause {
             #arguments_fields
-            pub(super) phantom_data: std::marker::PhantomData<&#arguments_lifetime ()>,
+            pub(super) phantom_data: std::marker::PhantomData<(&#arguments_lifetime (), #(&#trait_lifetimes (),)*)>,
         }
 
         #display_impl
@@ -93,6 +96,7 @@ fn debug_impl_fields<'a>(
 fn generate_arguments_fields(
     lifetime_rewriter: &mut LifetimeRewriter<UniformLifetimeGenerator>,
     method_inputs: &MethodInputs,
+    visibility: &Visibility,
 ) -> TokenStream {
     method_inputs
         .args
@@ -103,7 +107,8 @@ fn generate_arguments_fields(
 
             visit_type_mut(lifetime_rewriter, &mut ty);
 
-            quote! { pub(super) #ident: #ty, }
+            // The fields are accessed by closures passed to `returns_with`.
+            quote! { #visibility #ident: #ty, }
         })
         .collect()
 }
@@ -6,7 +6,7 @@ use super::constant::{
 use super::debug_impl::{generate_debug_impl, DebugImplField};
 use super::ArgumentsMatcherGenerator;
 use super::MethodDeclMetadata;
-use crate::code_generator_impl::util::ident_to_string_literal;
+use crate::code_generator_impl::util::{ident_to_string_literal, thread_safety_bounds};
 use crate::parse::method_decl::MethodDecl;
 use crate::parse::method_inputs::{MethodArg, MethodInputs};
 use proc_macro2::TokenStream;
@@ -24,7 +24,12 @@ impl ArgumentsMatcherGeneratorImpl {
 }
 
 impl ArgumentsMatcherGenerator for ArgumentsMatcherGeneratorImpl {
-    fn generate(&self, method: &MethodDeclMetadata, visibility: &Visibility) -> TokenStream {
+    fn generate(
+        &self,
+        method: &MethodDeclMetadata,
+        visibility: &Visibility,
+        sync: bool,
+    ) -> TokenStream {
         let MethodDeclMetadata {
             method_decl,
             generics,
@@ -32,14 +37,17 @@ impl ArgumentsMatcherGenerator for ArgumentsMatcherGeneratorImpl {
         } = method;
         let arguments_matcher_ident = arguments_matcher_ident(&method_decl.ident);
 
+        let trait_lifetimes: Vec<_> = generics.lifetimes().map(|def| &def.lifetime).collect();
+
         let mut generics = generics.clone();
         generics.params.push(mock_lifetime_as_generic_param());
 
-        let arguments_matcher_fields = arguments_matcher_fields(&method_decl.inputs);
+        let arguments_matcher_fields = arguments_matcher_fields(&method_decl.inputs, sync);
         let (_, ty_generics, where_clause) = generics.split_for_impl();
 
         let display_impl = generate_display_impl(method_decl, &generics);
         let arguments_matcher_impl = generate_arguments_matcher_impl(method, &generics);
+        let has_default_impl_impl = generate_has_default_impl_impl(method_decl, &generics);
 
         let debug_impl = generate_debug_impl(
             debug_impl_fields(method_decl),
@@ -53,16 +61,32 @@ impl ArgumentsMatcherGenerator for ArgumentsMatcherGeneratorImpl {
             #[doc(hidden)]
             #visibility struct #arguments_matcher_ident #ty_generics #where_clause {
                 #arguments_matcher_fields
-                pub(super) phantom_data: std::marker::PhantomData<&#mock_lifetime ()>,
+                pub(super) phantom_data: std::marker::PhantomData<(&#mock_lifetime (), #(&#trait_lifetimes (),)*)>,
             }
 
             #display_impl
             #debug_impl
             #arguments_matcher_impl
+            #has_default_impl_impl
         }
     }
 }
 
+/// Marks the argument matchers of methods with a default implementation,
+/// so that `calls_default` is only available for these methods.
+fn generate_has_default_impl_impl(method_decl: &MethodDecl, generics: &Generics) -> TokenStream {
+    if method_decl.default_impl.is_none() {
+        return TokenStream::new();
+    }
+
+    let arguments_matcher_ident = arguments_matcher_ident(&method_decl.ident);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics mockiato::internal::HasDefaultImpl for #arguments_matcher_ident #ty_generics #where_clause {}
+    }
+}
+
 /// Generates a `Display` implementation for an argument matcher.
 fn generate_display_impl(method_decl: &MethodDecl, generics: &Generics) -> TokenStream {
     let method_name_str = ident_to_string_literal(&method_decl.ident);
@@ -120,6 +144,7 @@ fn generate_arguments_matcher_impl(
     let (_, arguments_ty_generics, _) = arguments_struct_generics.split_for_impl();
 
     let matches_argument_method = generate_matches_arguments_method_impl(method_decl);
+    let on_call_method = generate_on_call_method_impl(method_decl);
     let arguments_lifetime = arguments_lifetime();
 
     quote! {
@@ -127,6 +152,8 @@ fn generate_arguments_matcher_impl(
             type Arguments = #arguments_struct_ident #arguments_ty_generics;
 
             #matches_argument_method
+
+            #on_call_method
         }
     }
 }
@@ -147,6 +174,21 @@ fn generate_matches_arguments_method_impl(method_decl: &MethodDecl) -> TokenStre
     }
 }
 
+fn generate_on_call_method_impl(method_decl: &MethodDecl) -> TokenStream {
+    let arg_idents: Vec<_> = method_decl
+        .inputs
+        .args
+        .iter()
+        .map(|arg| &arg.ident)
+        .collect();
+
+    quote! {
+        fn on_call(&self, args: &Self::Arguments) {
+            #( self.#arg_idents.on_call(&args.#arg_idents); )*
+        }
+    }
+}
+
 fn generate_matches_argument_calls(args: &[MethodArg]) -> TokenStream {
     let matches_argument_calls: Punctuated<_, Token![&&]> = args
         .iter()
@@ -158,8 +200,9 @@ fn generate_matches_argument_calls(args: &[MethodArg]) -> TokenStream {
     quote!(#matches_argument_calls)
 }
 
-fn arguments_matcher_fields(method_inputs: &MethodInputs) -> TokenStream {
+fn arguments_matcher_fields(method_inputs: &MethodInputs, sync: bool) -> TokenStream {
     let mock_lifetime = mock_lifetime();
+    let thread_safety_bounds = thread_safety_bounds(sync);
     method_inputs
         .args
         .iter()
@@ -169,7 +212,7 @@ fn arguments_matcher_fields(method_inputs: &MethodInputs) -> TokenStream {
             let bound_lifetimes = rewrite_lifetimes_incrementally(&mut ty);
 
             quote! {
-                pub(super) #ident: std::boxed::Box<dyn #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> + #mock_lifetime>,
+                pub(super) #ident: std::boxed::Box<dyn #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> #thread_safety_bounds + #mock_lifetime>,
             }
         })
         .collect()
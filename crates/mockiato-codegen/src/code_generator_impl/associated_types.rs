@@ -0,0 +1,143 @@
+use crate::parse::method_decl::MethodDecl;
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
+use syn::visit_mut::{visit_type_mut, VisitMut};
+use syn::{parse_quote, GenericParam, Generics, Ident, Path, Type, TypeParam, TypePath};
+
+/// Associated types are turned into generic type parameters of the mock.
+/// This function returns the trait's generics extended by these type parameters.
+/// Bounds of the associated types are moved to the where clause.
+pub(super) fn generics_with_associated_types(trait_decl: &TraitDecl) -> Generics {
+    let mut generics = trait_decl.generics.clone();
+
+    for associated_type in &trait_decl.associated_types {
+        let ident = &associated_type.ident;
+        let bounds = &associated_type.bounds;
+
+        generics
+            .params
+            .push(GenericParam::Type(TypeParam::from(ident.clone())));
+
+        if !bounds.is_empty() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ident: #bounds));
+        }
+    }
+
+    generics
+}
+
+/// Replaces all references to associated types (e.g. `Self::Error`)
+/// with the corresponding generic type parameter of the mock.
+pub(super) fn resolve_associated_types(trait_decl: &TraitDecl) -> TraitDecl {
+    let mut trait_decl = trait_decl.clone();
+    let mut resolver = AssociatedTypeResolver {
+        associated_types: trait_decl
+            .associated_types
+            .iter()
+            .map(|associated_type| associated_type.ident.clone())
+            .collect(),
+    };
+
+    if resolver.associated_types.is_empty() {
+        return trait_decl;
+    }
+
+    resolver.visit_generics_mut(&mut trait_decl.generics);
+
+    for associated_type in &mut trait_decl.associated_types {
+        for bound in associated_type.bounds.iter_mut() {
+            resolver.visit_type_param_bound_mut(bound);
+        }
+    }
+
+    for associated_const in &mut trait_decl.associated_consts {
+        resolver.visit_type_mut(&mut associated_const.ty);
+    }
+
+    for method_decl in &mut trait_decl.methods {
+        resolve_associated_types_in_method_decl(&mut resolver, method_decl);
+    }
+
+    trait_decl
+}
+
+/// Generates the definitions of the associated types for the trait impl.
+pub(super) fn generate_associated_types(trait_decl: &TraitDecl) -> TokenStream {
+    trait_decl
+        .associated_types
+        .iter()
+        .map(|associated_type| {
+            let ident = &associated_type.ident;
+            quote! { type #ident = #ident; }
+        })
+        .collect()
+}
+
+fn resolve_associated_types_in_method_decl(
+    resolver: &mut AssociatedTypeResolver,
+    method_decl: &mut MethodDecl,
+) {
+    resolver.visit_generics_mut(&mut method_decl.generics);
+    resolver.visit_return_type_mut(&mut method_decl.output);
+
+    for argument in &mut method_decl.inputs.args {
+        resolver.visit_type_mut(&mut argument.ty);
+    }
+}
+
+struct AssociatedTypeResolver {
+    associated_types: HashSet<Ident>,
+}
+
+impl AssociatedTypeResolver {
+    fn associated_type_ident<'a>(&self, type_path: &'a TypePath) -> Option<&'a Ident> {
+        let TypePath { qself, path } = type_path;
+
+        let ident = match qself {
+            None if path.segments.len() == 2 && path.segments[0].ident == "Self" => {
+                &path.segments[1].ident
+            }
+            Some(qself) if is_self_type(&qself.ty) && path.segments.len() == qself.position + 1 => {
+                &path.segments[qself.position].ident
+            }
+            _ => return None,
+        };
+
+        // The guards above ensure that the path has at least one segment.
+        let last_segment = &path.segments[path.segments.len() - 1];
+
+        if last_segment.arguments.is_empty() && self.associated_types.contains(ident) {
+            Some(ident)
+        } else {
+            None
+        }
+    }
+}
+
+impl VisitMut for AssociatedTypeResolver {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(type_path) = ty {
+            if let Some(ident) = self.associated_type_ident(type_path).cloned() {
+                *ty = Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from(ident),
+                });
+                return;
+            }
+        }
+
+        visit_type_mut(self, ty);
+    }
+}
+
+fn is_self_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path.is_ident("Self"),
+        _ => false,
+    }
+}
@@ -0,0 +1,23 @@
+use super::verify::{method_idents, supertrait_mock_field_idents};
+use crate::parse::mockable_attr::SupertraitMock;
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `checkpoint` method of the `Mock` trait, which verifies the expected calls
+/// of all methods and removes them afterwards.
+pub(super) fn generate_checkpoint_method(
+    trait_decl: &TraitDecl,
+    supertrait_mocks: &[SupertraitMock],
+) -> TokenStream {
+    let method_idents = method_idents(trait_decl);
+    let supertrait_mock_field_idents = supertrait_mock_field_idents(supertrait_mocks);
+
+    quote! {
+        fn checkpoint(&mut self) {
+            #( self.#method_idents.verify_unwrap(); )*
+            #( mockiato::internal::Mock::checkpoint(&mut self.#supertrait_mock_field_idents); )*
+            #( self.#method_idents.reset(); )*
+        }
+    }
+}
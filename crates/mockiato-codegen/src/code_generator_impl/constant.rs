@@ -26,6 +26,10 @@ pub(super) fn mock_lifetime() -> Lifetime {
     parse_quote!('mock)
 }
 
+pub(super) fn static_lifetime() -> Lifetime {
+    parse_quote!('static)
+}
+
 pub(super) fn mock_lifetime_as_generic_param() -> GenericParam {
     lifetime_to_generic_param(mock_lifetime())
 }
@@ -40,6 +44,61 @@ pub(super) fn mock_struct_ident(trait_decl: &TraitDecl) -> Ident {
     )
 }
 
+/// Generates the identifier of the field holding the mock for a supertrait.
+/// Also used as the name of the accessor method.
+pub(super) fn supertrait_mock_field_ident(supertrait_mock_ident: &Ident) -> Ident {
+    Ident::new(
+        &supertrait_mock_ident.to_string().to_snake_case(),
+        supertrait_mock_ident.span(),
+    )
+}
+
+/// Generates the identifier of the macro that implements a mock's trait
+/// for another mock by forwarding all calls to it.
+pub(super) fn forward_to_mock_macro_ident(mock_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "__mockiato_forward_to_";
+
+    Ident::new(
+        &format!(
+            "{}{}",
+            IDENTIFIER_PREFIX,
+            mock_ident.to_string().to_snake_case()
+        ),
+        mock_ident.span(),
+    )
+}
+
+/// Generates the identifier of the guard holding the expectations for associated functions
+pub(super) fn static_guard_ident(mock_ident: &Ident) -> Ident {
+    const IDENTIFIER_SUFFIX: &str = "StaticGuard";
+
+    Ident::new(
+        &format!("{}{}", mock_ident, IDENTIFIER_SUFFIX),
+        mock_ident.span(),
+    )
+}
+
+/// Generates the identifier of the internal struct holding the methods for associated functions
+pub(super) fn static_methods_ident() -> Ident {
+    parse_quote!(StaticMethods)
+}
+
+/// Generates the identifier of the internal trait holding copies of the default implementations
+pub(super) fn default_impls_trait_ident() -> Ident {
+    parse_quote!(DefaultImpls)
+}
+
+/// Generates the identifier of the copy of a method's default implementation.
+/// The prefix prevents ambiguities with the methods of the mocked trait.
+pub(super) fn default_impl_method_ident(method_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "__mockiato_default_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_ident),
+        method_ident.span(),
+    )
+}
+
 /// Generates a [`struct@Ident`] for the internal sub-mod
 /// for `Arguments` and `ArgumentsMatcher` impls for a mock struct.
 pub(super) fn mod_ident(mock_ident: &Ident) -> Ident {
@@ -65,6 +124,26 @@ pub(super) fn expect_method_ident(method_decl_ident: &Ident) -> Ident {
     )
 }
 
+/// Generates the identifier for a stub method
+pub(super) fn stub_method_ident(method_decl_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "stub_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident),
+        method_decl_ident.span(),
+    )
+}
+
+/// Generates the identifier for an associated function returning the recorded calls of a mocked method
+pub(super) fn recorded_calls_method_ident(method_decl_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "calls_to_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident),
+        method_decl_ident.span(),
+    )
+}
+
 /// Generates the method identifier for a method configuring calls to be expected sequentially.
 pub(super) fn expect_method_calls_in_order_ident(method_decl: &MethodDecl) -> Ident {
     const IDENTIFIER_PREFIX: &str = "expect_";
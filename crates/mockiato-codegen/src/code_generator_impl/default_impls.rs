@@ -0,0 +1,130 @@
+use super::constant::{default_impl_method_ident, default_impls_trait_ident};
+use super::visibility::raise_visibility_by_one_level;
+use super::{GenerateMockParameters, MethodDeclMetadata};
+use crate::parse::method_decl::{DefaultMethodImpl, MethodDecl};
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A trait impl can't call the default implementation of a method that it overrides.
+/// The mock calls a copy of the default implementation instead, which is
+/// declared in a hidden trait that has the mocked trait as supertrait.
+pub(super) fn has_default_impls(trait_decl: &TraitDecl) -> bool {
+    trait_decl
+        .methods
+        .iter()
+        .any(|method_decl| method_decl.default_impl.is_some())
+}
+
+/// Generates the trait holding the copies of the default implementations.
+/// This trait is part of the internal sub-mod.
+///
+/// The trait declaration is passed in before associated types are resolved,
+/// since the copied signatures and bodies still refer to them using `Self::`.
+pub(super) fn generate_default_impls_trait(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    if !has_default_impls(trait_decl) {
+        return TokenStream::new();
+    }
+
+    let visibility = raise_visibility_by_one_level(&trait_decl.visibility);
+    let default_impls_trait_ident = default_impls_trait_ident();
+    let trait_path = &parameters.trait_path;
+    let (impl_generics, ty_generics, where_clause) = trait_decl.generics.split_for_impl();
+
+    let methods: TokenStream = trait_decl
+        .methods
+        .iter()
+        .filter_map(|method_decl| {
+            let DefaultMethodImpl { signature, body } = method_decl.default_impl.as_ref()?;
+            let mut signature = signature.clone();
+            signature.ident = default_impl_method_ident(&method_decl.ident);
+
+            Some(quote! { #signature #body })
+        })
+        .collect();
+
+    // Lints for the bodies are already reported for the original default implementations.
+    quote! {
+        #[doc(hidden)]
+        #[allow(unused_variables, unused_mut, unused_assignments, unused_must_use, clippy::all)]
+        #visibility trait #default_impls_trait_ident #impl_generics: #trait_path #ty_generics #where_clause {
+            #methods
+        }
+    }
+}
+
+/// Implements the trait holding the copies of the default implementations for the mock.
+pub(super) fn generate_default_impls_trait_impl(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    if !has_default_impls(trait_decl) {
+        return TokenStream::new();
+    }
+
+    let mock_struct_ident = &parameters.mock_struct_ident;
+    let mod_ident = &parameters.mod_ident;
+    let default_impls_trait_ident = default_impls_trait_ident();
+    let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
+    let (_, trait_ty_generics, _) = trait_decl.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #mod_ident::#default_impls_trait_ident #trait_ty_generics
+            for #mock_struct_ident #ty_generics #where_clause {}
+    }
+}
+
+/// Wraps the call to the method's expectations so that the default implementation
+/// is called when the matching expected call requests it.
+/// `call` has to evaluate to a `CallOutcome`.
+pub(super) fn generate_call_with_default_impl(
+    MethodDeclMetadata {
+        method_decl,
+        arguments_struct_ident,
+        ..
+    }: &MethodDeclMetadata,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+    call: TokenStream,
+) -> TokenStream {
+    let MethodDecl {
+        ident,
+        asyncness,
+        generics,
+        inputs,
+        ..
+    } = method_decl;
+
+    let mod_ident = &parameters.mod_ident;
+    let default_impls_trait_ident = default_impls_trait_ident();
+    let default_impl_method_ident = default_impl_method_ident(ident);
+    let (_, trait_ty_generics, _) = trait_decl.generics.split_for_impl();
+
+    let argument_idents: Vec<_> = inputs.args.iter().map(|argument| &argument.ident).collect();
+    let self_arg = inputs.self_arg.as_ref().map(|_| quote! { self, });
+
+    let type_params: Vec<_> = generics.type_params().map(|param| &param.ident).collect();
+    let turbofish = if type_params.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! { ::<#(#type_params),*> }
+    };
+
+    let await_call = asyncness.map(|_| quote! { .await });
+
+    quote! {
+        match #call {
+            mockiato::internal::CallOutcome::Return(return_value) => return_value,
+            mockiato::internal::CallOutcome::CallDefault(arguments) => {
+                let #mod_ident::#arguments_struct_ident { #(#argument_idents,)* .. } = arguments;
+
+                <Self as #mod_ident::#default_impls_trait_ident #trait_ty_generics>::#default_impl_method_ident #turbofish(
+                    #self_arg #(#argument_idents),*
+                ) #await_call
+            }
+        }
+    }
+}
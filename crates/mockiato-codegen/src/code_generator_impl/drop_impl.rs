@@ -11,6 +11,7 @@ pub(crate) fn generate_drop_impl(
     let verify_calls: TokenStream = trait_decl
         .methods
         .iter()
+        .filter(|method_decl| method_decl.has_self_arg())
         .map(generate_verify_call)
         .collect();
 
@@ -32,6 +33,6 @@ fn generate_verify_call(method_decl: &MethodDecl) -> TokenStream {
     let ident = &method_decl.ident;
 
     quote! {
-        self.#ident.verify_unwrap();
+        self.#ident.verify_remaining_unwrap();
     }
 }
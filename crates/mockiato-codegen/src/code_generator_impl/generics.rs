@@ -3,7 +3,7 @@ use crate::syn_ext::PathExt;
 use std::collections::HashSet;
 use syn::visit::{visit_path, Visit};
 use syn::{
-    GenericParam, Generics, Ident, Path, PredicateType, Type, TypeParam, WhereClause,
+    parse_quote, GenericParam, Generics, Ident, Path, PredicateType, Type, TypeParam, WhereClause,
     WherePredicate,
 };
 
@@ -18,10 +18,15 @@ pub(super) fn get_matching_generics_for_method_inputs(
         .as_ref()
         .map(|where_clause| filter_where_clause(where_clause, &matching_generic_types));
 
-    let params = matching_generic_types
-        .into_iter()
-        .map(|ident| GenericParam::Type(TypeParam::from(ident.clone())))
-        .collect();
+    // Lifetimes are always included, as they might be referenced by the where clause.
+    // Iterating over the declared generics instead of the set keeps
+    // the parameters in the same order for every generated item.
+    let lifetimes = generics.lifetimes().cloned().map(GenericParam::Lifetime);
+    let type_params = generics
+        .type_params()
+        .filter(|param| matching_generic_types.contains(&param.ident))
+        .map(|param| GenericParam::Type(TypeParam::from(param.ident.clone())));
+    let params = lifetimes.chain(type_params).collect();
 
     Generics {
         lt_token: generics.lt_token,
@@ -31,6 +36,78 @@ pub(super) fn get_matching_generics_for_method_inputs(
     }
 }
 
+/// Combines the generics of a trait with the type parameters of one of its methods.
+/// Bounds of the method's type parameters are moved to the where clause.
+pub(super) fn combine_trait_and_method_generics(
+    trait_generics: &Generics,
+    method_generics: &Generics,
+) -> Generics {
+    let mut generics = trait_generics.clone();
+
+    for type_param in method_generics.type_params() {
+        let ident = &type_param.ident;
+        let bounds = &type_param.bounds;
+
+        generics
+            .params
+            .push(GenericParam::Type(TypeParam::from(ident.clone())));
+
+        if !bounds.is_empty() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ident: #bounds));
+        }
+    }
+
+    let method_where_predicates = where_predicates_for_type_params(method_generics);
+
+    if !method_where_predicates.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(method_where_predicates);
+    }
+
+    generics
+}
+
+/// Returns the where predicates that restrict a type parameter declared in the given generics.
+pub(super) fn where_predicates_for_type_params(generics: &Generics) -> Vec<WherePredicate> {
+    let type_params: HashSet<_> = generics.type_params().map(|param| &param.ident).collect();
+
+    generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates)
+        .filter(|predicate| match predicate {
+            WherePredicate::Type(PredicateType { bounded_ty, .. }) => {
+                match first_path_segment_ident_from_type(bounded_ty) {
+                    Some(ident) => type_params.contains(ident),
+                    None => false,
+                }
+            }
+            _ => false,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Generates a tuple containing the type parameters of a method (e.g. `(T, U,)`),
+/// which identifies an instantiation of the method. Returns `None` for non-generic methods.
+pub(super) fn type_params_as_tuple(method_generics: &Generics) -> Option<Type> {
+    let type_params: Vec<_> = method_generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+
+    if type_params.is_empty() {
+        None
+    } else {
+        Some(parse_quote!((#(#type_params,)*)))
+    }
+}
+
 fn filter_where_clause(
     WhereClause {
         predicates,
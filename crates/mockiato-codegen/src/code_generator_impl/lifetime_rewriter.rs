@@ -13,6 +13,7 @@ where
     T: LifetimeGenerator,
 {
     pub(super) generator: T,
+    preserved_lifetimes: Vec<Lifetime>,
 }
 
 impl<T> LifetimeRewriter<T>
@@ -20,7 +21,18 @@ where
     T: LifetimeGenerator,
 {
     pub(super) fn new(generator: T) -> Self {
-        Self { generator }
+        Self::with_preserved_lifetimes(generator, Vec::new())
+    }
+
+    /// Creates a rewriter that leaves the given lifetimes untouched
+    pub(super) fn with_preserved_lifetimes(
+        generator: T,
+        preserved_lifetimes: Vec<Lifetime>,
+    ) -> Self {
+        Self {
+            generator,
+            preserved_lifetimes,
+        }
     }
 }
 
@@ -29,7 +41,9 @@ where
     T: LifetimeGenerator,
 {
     fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
-        *lifetime = self.generator.generate_lifetime();
+        if !self.preserved_lifetimes.contains(lifetime) {
+            *lifetime = self.generator.generate_lifetime();
+        }
     }
 
     fn visit_type_reference_mut(&mut self, type_reference: &mut TypeReference) {
@@ -1,60 +1,115 @@
 use super::bound_lifetimes::rewrite_lifetimes_incrementally;
+use super::checkpoint::generate_checkpoint_method;
 use super::constant::{
     arguments_matcher_ident, expect_method_calls_in_order_ident, expect_method_ident,
-    generic_parameter_ident, mock_lifetime, mock_lifetime_as_generic_param,
+    generic_parameter_ident, mock_lifetime, mock_lifetime_as_generic_param, static_lifetime,
+    stub_method_ident, supertrait_mock_field_ident,
 };
 use super::debug_impl::{generate_debug_impl, DebugImplField};
-use super::generics::get_matching_generics_for_method_inputs;
+use super::generics::{type_params_as_tuple, where_predicates_for_type_params};
 use super::lifetime_rewriter::{LifetimeRewriter, UniformLifetimeGenerator};
+use super::recorded_calls::{
+    generate_collect_recorded_calls_method, generate_recorded_calls_methods,
+};
+use super::static_methods::generate_static_expectations_method;
+use super::supertraits::{
+    generate_supertrait_mock_accessors, generate_supertrait_mock_fields,
+    generate_supertrait_mock_initializer_fields,
+};
+use super::verify::generate_collect_unsatisfied_expectations_method;
 use super::GenerateMockParameters;
 use super::MethodDeclMetadata;
-use crate::code_generator_impl::util::doc_attribute;
+use crate::code_generator_impl::util::{
+    doc_attribute, lifetime_to_generic_param, thread_safety_bounds, thread_safety_marker,
+    trait_method_anchor,
+};
 use crate::parse::method_decl::MethodDecl;
 use crate::parse::method_inputs::MethodArg;
+use crate::parse::mockable_attr::SupertraitMock;
 use crate::parse::trait_decl::TraitDecl;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::visit_mut::visit_type_mut;
-use syn::{parse_quote, GenericParam, Ident, LitStr, Token, Type, TypeParam, WherePredicate};
+use syn::{
+    parse_quote, GenericParam, Generics, Ident, Lifetime, LitStr, Token, Type, TypeParam,
+    WherePredicate,
+};
 
 type ArgumentsWithGenerics<'a> = &'a [(Ident, &'a MethodArg)];
 
 pub(crate) fn generate_mock_struct(
     trait_decl: &TraitDecl,
     parameters: &'_ GenerateMockParameters,
+    supertrait_mocks: &[SupertraitMock],
 ) -> TokenStream {
     let mock_struct_ident = &parameters.mock_struct_ident;
     let mod_ident = &parameters.mod_ident;
 
-    let method_fields: TokenStream = parameters
-        .methods
-        .iter()
-        .map(|method| generate_method_field(method, mod_ident))
+    let methods = || {
+        parameters
+            .methods
+            .iter()
+            .filter(|method| method.method_decl.has_self_arg())
+    };
+
+    let method_fields: TokenStream = methods()
+        .map(|method| generate_method_field(method, mod_ident, parameters.sync))
         .collect();
 
-    let initializer_fields: TokenStream = parameters
-        .methods
-        .iter()
+    let initializer_fields: TokenStream = methods()
         .map(|method| generate_initializer_field(method, mock_struct_ident))
         .collect();
 
-    let expect_methods: TokenStream = parameters
-        .methods
-        .iter()
-        .map(|method| generate_expect_method(method, trait_decl, mod_ident))
+    let expect_methods: TokenStream = methods()
+        .map(|method| generate_mock_expect_method(method, trait_decl, parameters))
         .collect();
 
-    let expect_method_call_in_order_methods: TokenStream = trait_decl
-        .methods
-        .iter()
-        .map(|method_decl| generate_expect_method_calls_in_order_method(trait_decl, method_decl))
+    let stub_methods: TokenStream = methods()
+        .map(|method| generate_stub_method(method, trait_decl, parameters))
+        .collect();
+
+    let expect_method_call_in_order_methods: TokenStream = methods()
+        .map(|method| {
+            generate_expect_method_calls_in_order_method(
+                trait_decl,
+                &method.method_decl,
+                &quote! { self },
+            )
+        })
         .collect();
 
-    let debug_impl_fields = parameters
-        .methods
+    let collect_recorded_calls_method =
+        generate_collect_recorded_calls_method(trait_decl, supertrait_mocks);
+    let recorded_calls_methods = generate_recorded_calls_methods(trait_decl);
+
+    let collect_unsatisfied_expectations_method =
+        generate_collect_unsatisfied_expectations_method(trait_decl, supertrait_mocks);
+    let checkpoint_method = generate_checkpoint_method(trait_decl, supertrait_mocks);
+
+    let static_expectations_method = generate_static_expectations_method(trait_decl, parameters);
+
+    let supertrait_mock_fields = generate_supertrait_mock_fields(supertrait_mocks);
+    let supertrait_mock_initializer_fields =
+        generate_supertrait_mock_initializer_fields(supertrait_mocks);
+    let supertrait_mock_accessors =
+        generate_supertrait_mock_accessors(trait_decl, supertrait_mocks);
+
+    let supertrait_mock_field_idents: Vec<_> = supertrait_mocks
         .iter()
-        .map(|method| debug_impl_field(&method.method_decl));
+        .map(|supertrait_mock| supertrait_mock_field_ident(&supertrait_mock.mock_struct_ident))
+        .collect();
+
+    let debug_impl_fields = methods()
+        .map(|method| debug_impl_field(&method.method_decl))
+        .chain(
+            supertrait_mock_field_idents
+                .iter()
+                .map(|ident| DebugImplField {
+                    ident,
+                    expression: quote! { self.#ident },
+                }),
+        );
 
     let debug_impl =
         generate_debug_impl(debug_impl_fields, mock_struct_ident, &parameters.generics);
@@ -72,14 +127,19 @@ pub(crate) fn generate_mock_struct(
     ));
 
     let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
-    let mock_lifetime = mock_lifetime();
+
+    // The fields of generic methods are type-erased and don't necessarily
+    // reference the mock's generic parameters.
+    let lifetimes = parameters.generics.lifetimes().map(|def| &def.lifetime);
+    let type_params = parameters.generics.type_params().map(|param| &param.ident);
 
     quote! {
         #[derive(Clone)]
         #documentation
         #visibility struct #mock_struct_ident #ty_generics #where_clause {
             #method_fields
-            phantom_data: std::marker::PhantomData<&#mock_lifetime ()>,
+            #supertrait_mock_fields
+            phantom_data: std::marker::PhantomData<(#(&#lifetimes (),)* #(fn() -> #type_params,)*)>,
         }
 
         impl #impl_generics #mock_struct_ident #ty_generics #where_clause {
@@ -87,13 +147,30 @@ pub(crate) fn generate_mock_struct(
             #visibility fn new() -> Self {
                 Self {
                     #initializer_fields
+                    #supertrait_mock_initializer_fields
                     phantom_data: std::marker::PhantomData,
                 }
             }
 
             #expect_methods
 
+            #stub_methods
+
             #expect_method_call_in_order_methods
+
+            #recorded_calls_methods
+
+            #static_expectations_method
+
+            #supertrait_mock_accessors
+        }
+
+        impl #impl_generics mockiato::internal::Mock for #mock_struct_ident #ty_generics #where_clause {
+            #checkpoint_method
+
+            #collect_unsatisfied_expectations_method
+
+            #collect_recorded_calls_method
         }
 
         #debug_impl
@@ -109,24 +186,42 @@ pub(crate) fn generate_mock_struct(
 
 fn generate_method_field(
     MethodDeclMetadata {
-        method_decl: MethodDecl { ident, .. },
+        method_decl:
+            MethodDecl {
+                ident,
+                generics: method_generics,
+                ..
+            },
         arguments_matcher_struct_ident,
         generics,
         return_type,
         ..
     }: &MethodDeclMetadata,
     mod_ident: &Ident,
+    sync: bool,
 ) -> TokenStream {
-    let return_type = rewrite_lifetimes_to_mock_lifetime(return_type);
+    let mock_lifetime = mock_lifetime();
+    let thread_safety_marker = thread_safety_marker(sync);
+
+    if type_params_as_tuple(method_generics).is_some() {
+        return quote! {
+            #ident: mockiato::internal::GenericMethod<#thread_safety_marker>,
+        };
+    }
+
+    let return_type = rewrite_lifetimes_to_mock_lifetime(return_type, generics);
 
     let mut generics = generics.clone();
     generics.params.push(mock_lifetime_as_generic_param());
     let (_, ty_generics, _) = generics.split_for_impl();
 
-    let mock_lifetime = mock_lifetime();
-
     quote! {
-        #ident: mockiato::internal::Method<#mock_lifetime, #mod_ident::#arguments_matcher_struct_ident #ty_generics, #return_type>,
+        #ident: mockiato::internal::Method<
+            #mock_lifetime,
+            #mod_ident::#arguments_matcher_struct_ident #ty_generics,
+            #return_type,
+            #thread_safety_marker
+        >,
     }
 }
 
@@ -144,31 +239,68 @@ fn generate_initializer_field(
         Span::call_site(),
     );
 
-    quote! {
-        #method_ident: mockiato::internal::Method::new(#name),
+    if type_params_as_tuple(&method.method_decl.generics).is_some() {
+        quote! {
+            #method_ident: mockiato::internal::GenericMethod::new(#name),
+        }
+    } else {
+        quote! {
+            #method_ident: mockiato::internal::Method::new(#name),
+        }
     }
 }
 
-fn generate_expect_method(
+fn generate_mock_expect_method(
+    method: &MethodDeclMetadata,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let method_ident = &method.method_decl.ident;
+    let add_expected_call = match type_params_as_tuple(&method.method_decl.generics) {
+        Some(type_params) => quote! { add_expected_call::<#type_params, _, _> },
+        None => quote! { add_expected_call },
+    };
+
+    generate_expect_method(
+        method,
+        trait_decl,
+        parameters,
+        &expectations_lifetime(&method.method_decl.generics),
+        |matcher| {
+            quote! { self.#method_ident.#add_expected_call(#matcher) }
+        },
+    )
+}
+
+/// Generates an `expect_*` method. The matcher is stored in a method
+/// that outlives the given `lifetime` using the expression returned by `add_expected_call`.
+pub(super) fn generate_expect_method(
     MethodDeclMetadata {
         return_type,
-        method_decl:
-            MethodDecl {
-                ident: method_ident,
-                inputs,
-                ..
-            },
+        method_decl,
+        generics: arguments_struct_generics,
         ..
     }: &MethodDeclMetadata,
     TraitDecl {
         visibility,
-        generics,
         ident: trait_ident,
         ..
     }: &TraitDecl,
-    mod_ident: &Ident,
+    GenerateMockParameters {
+        mod_ident, sync, ..
+    }: &GenerateMockParameters,
+    lifetime: &Lifetime,
+    add_expected_call: impl FnOnce(TokenStream) -> TokenStream,
 ) -> TokenStream {
+    let MethodDecl {
+        ident: method_ident,
+        generics: method_generics,
+        inputs,
+        ..
+    } = method_decl;
+
     let expect_method_ident = expect_method_ident(method_ident);
+    let thread_safety_marker = thread_safety_marker(*sync);
 
     let arguments_with_generics: Vec<_> = inputs
         .args
@@ -183,7 +315,7 @@ fn generate_expect_method(
         .collect();
 
     let arguments_matcher_ident = arguments_matcher_ident(method_ident);
-    let return_type = rewrite_lifetimes_to_mock_lifetime(return_type);
+    let return_type = rewrite_lifetimes(return_type, arguments_struct_generics, lifetime);
 
     let expected_parameters: TokenStream = arguments_with_generics
         .iter()
@@ -203,19 +335,37 @@ fn generate_expect_method(
         "Expects a call to [`{0}::{1}`],
 panicking if the function was not called by the time the object goes out of scope.
 
-[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
-        trait_ident, method_ident,
+[`{0}::{1}`]: ./trait.{0}.html#{2}",
+        trait_ident,
+        method_ident,
+        trait_method_anchor(method_decl),
     ));
 
-    let mut arguments_struct_generics = get_matching_generics_for_method_inputs(inputs, generics);
+    let mut arguments_struct_generics = arguments_struct_generics.clone();
     arguments_struct_generics
         .params
-        .push(mock_lifetime_as_generic_param());
-    let generics = argument_generics(&arguments_with_generics);
-    let where_clause = where_clause(&arguments_with_generics);
+        .push(lifetime_to_generic_param(lifetime.clone()));
+
+    let mut generics = method_type_params(method_generics);
+    generics.extend(argument_generics(&arguments_with_generics));
+    let mut where_clause: Punctuated<_, Token![,]> =
+        where_predicates_for_type_params(method_generics)
+            .into_iter()
+            .collect();
+    where_clause.extend(where_clause_predicates(
+        &arguments_with_generics,
+        lifetime,
+        *sync,
+    ));
 
     let (_, ty_generics, _) = arguments_struct_generics.split_for_impl();
-    let mock_lifetime = mock_lifetime();
+
+    let add_expected_call = add_expected_call(quote! {
+        #mod_ident::#arguments_matcher_ident {
+            #expected_parameters
+            phantom_data: std::marker::PhantomData,
+        }
+    });
 
     quote! {
         #must_use_annotation
@@ -224,33 +374,121 @@ panicking if the function was not called by the time the object goes out of scop
             &mut self,
             #arguments
         ) -> mockiato::MethodCallBuilder<
-            #mock_lifetime,
+            #lifetime,
             '_,
             #mod_ident::#arguments_matcher_ident #ty_generics,
-            #return_type
+            #return_type,
+            #thread_safety_marker
         > where #where_clause
         {
             #[allow(dead_code)]
             let argument = mockiato::Argument::internal_new();
-            self.#method_ident.add_expected_call(
-                #mod_ident::#arguments_matcher_ident {
-                    #expected_parameters
-                    phantom_data: std::marker::PhantomData,
-                }
-            )
+            #add_expected_call
         }
     }
 }
 
-fn generate_expect_method_calls_in_order_method(
+/// Generates a `stub_*` associated function, which configures the behaviour
+/// for calls that don't match any expected call.
+/// It doesn't take `self`, so that it can't shadow a method of the mocked trait.
+fn generate_stub_method(
+    MethodDeclMetadata {
+        return_type,
+        method_decl,
+        generics: arguments_struct_generics,
+        ..
+    }: &MethodDeclMetadata,
+    TraitDecl {
+        visibility,
+        ident: trait_ident,
+        ..
+    }: &TraitDecl,
+    GenerateMockParameters {
+        mod_ident, sync, ..
+    }: &GenerateMockParameters,
+) -> TokenStream {
+    let MethodDecl {
+        ident: method_ident,
+        generics: method_generics,
+        inputs,
+        ..
+    } = method_decl;
+
+    let stub_method_ident = stub_method_ident(method_ident);
+    let thread_safety_marker = thread_safety_marker(*sync);
+    let lifetime = expectations_lifetime(method_generics);
+
+    let arguments_matcher_ident = arguments_matcher_ident(method_ident);
+    let return_type = rewrite_lifetimes(return_type, arguments_struct_generics, &lifetime);
+
+    let any_parameters: TokenStream = inputs
+        .args
+        .iter()
+        .map(|argument| &argument.ident)
+        .map(|argument_ident| quote! { #argument_ident: Box::new(argument.any()), })
+        .collect();
+
+    let set_stub = match type_params_as_tuple(method_generics) {
+        Some(type_params) => quote! { set_stub::<#type_params, _, _> },
+        None => quote! { set_stub },
+    };
+
+    let documentation = doc_attribute(format!(
+        "Stubs [`{0}::{1}`] for calls with any arguments that don't match an expected call
+or whose matching expected calls have been called the maximum number of times.
+The stub may be called any number of times and is not verified.
+
+[`{0}::{1}`]: ./trait.{0}.html#{2}",
+        trait_ident,
+        method_ident,
+        trait_method_anchor(method_decl),
+    ));
+
+    let mut arguments_struct_generics = arguments_struct_generics.clone();
+    arguments_struct_generics
+        .params
+        .push(lifetime_to_generic_param(lifetime.clone()));
+    let (_, ty_generics, _) = arguments_struct_generics.split_for_impl();
+
+    let generics = method_type_params(method_generics);
+    let where_clause = where_predicates_for_type_params(method_generics);
+
+    quote! {
+        #documentation
+        #visibility fn #stub_method_ident <#generics> (
+            mock: &mut Self,
+        ) -> mockiato::MethodCallBuilder<
+            #lifetime,
+            '_,
+            #mod_ident::#arguments_matcher_ident #ty_generics,
+            #return_type,
+            #thread_safety_marker
+        > where #(#where_clause,)*
+        {
+            #[allow(dead_code)]
+            let argument = mockiato::Argument::internal_new();
+            mock.#method_ident.#set_stub(#mod_ident::#arguments_matcher_ident {
+                #any_parameters
+                phantom_data: std::marker::PhantomData,
+            })
+        }
+    }
+}
+
+/// Generates an `expect_*_calls_in_order` method.
+/// `receiver` is the expression holding the method's expectations.
+pub(super) fn generate_expect_method_calls_in_order_method(
     trait_decl: &TraitDecl,
     method_decl: &MethodDecl,
+    receiver: &TokenStream,
 ) -> TokenStream {
     let documentation = doc_attribute(format!(
         "Configures [`{0}::{1}`] to expect calls in the order they were added in.
 
-[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
-        trait_decl.ident, method_decl.ident,
+[`{0}::{1}`]: ./trait.{0}.html#{2}",
+        trait_decl.ident,
+        method_decl.ident,
+        trait_method_anchor(method_decl),
     ));
 
     let visibility = &trait_decl.visibility;
@@ -261,7 +499,7 @@ fn generate_expect_method_calls_in_order_method(
     quote! {
         #documentation
         #visibility fn #ident(&mut self) {
-            self.#method_ident.expect_method_calls_in_order()
+            #receiver.#method_ident.expect_method_calls_in_order()
         }
     }
 }
@@ -281,11 +519,15 @@ fn is_empty_return_value(return_type: &Type) -> bool {
     }
 }
 
-fn where_clause(arguments: ArgumentsWithGenerics<'_>) -> Punctuated<WherePredicate, Token![,]> {
+fn where_clause_predicates(
+    arguments: ArgumentsWithGenerics<'_>,
+    lifetime: &Lifetime,
+    sync: bool,
+) -> Punctuated<WherePredicate, Token![,]> {
     arguments
         .iter()
         .map(|(generic_type_ident, method_argument)| {
-            where_clause_predicate(generic_type_ident, method_argument)
+            where_clause_predicate(generic_type_ident, method_argument, lifetime, sync)
         })
         .collect()
 }
@@ -293,16 +535,26 @@ fn where_clause(arguments: ArgumentsWithGenerics<'_>) -> Punctuated<WherePredica
 fn where_clause_predicate(
     generic_type_ident: &Ident,
     method_argument: &MethodArg,
+    lifetime: &Lifetime,
+    sync: bool,
 ) -> WherePredicate {
     let mut ty = method_argument.ty.clone();
     let bound_lifetimes = rewrite_lifetimes_incrementally(&mut ty);
-    let mock_lifetime = mock_lifetime();
+    let thread_safety_bounds = thread_safety_bounds(sync);
 
     parse_quote! {
-        #generic_type_ident: #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> + #mock_lifetime
+        #generic_type_ident: #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> #thread_safety_bounds + #lifetime
     }
 }
 
+fn method_type_params(method_generics: &Generics) -> Punctuated<GenericParam, Token![,]> {
+    method_generics
+        .type_params()
+        .cloned()
+        .map(GenericParam::Type)
+        .collect()
+}
+
 fn argument_generics(arguments: ArgumentsWithGenerics<'_>) -> Punctuated<GenericParam, Token![,]> {
     arguments
         .iter()
@@ -320,10 +572,33 @@ fn generate_argument((generic_type_ident, method_argument): &(Ident, &MethodArg)
     }
 }
 
-fn rewrite_lifetimes_to_mock_lifetime(ty: &Type) -> Type {
+/// Rewrites all lifetimes except the ones declared on the trait to the mock lifetime.
+/// The lifetime that the matchers and return values of a method's expectations need to outlive.
+/// The expectations of generic methods are type-erased using [`std::any::Any`],
+/// which requires them to be `'static`.
+pub(super) fn expectations_lifetime(method_generics: &Generics) -> Lifetime {
+    if type_params_as_tuple(method_generics).is_some() {
+        static_lifetime()
+    } else {
+        mock_lifetime()
+    }
+}
+
+fn rewrite_lifetimes_to_mock_lifetime(ty: &Type, generics: &Generics) -> Type {
+    rewrite_lifetimes(ty, generics, &mock_lifetime())
+}
+
+/// Rewrites all lifetimes except the ones declared on the trait to the given lifetime.
+pub(super) fn rewrite_lifetimes(ty: &Type, generics: &Generics, lifetime: &Lifetime) -> Type {
     let mut ty = ty.clone();
-    let mut lifetime_rewriter =
-        LifetimeRewriter::new(UniformLifetimeGenerator::new(mock_lifetime()));
+    let trait_lifetimes = generics
+        .lifetimes()
+        .map(|def| def.lifetime.clone())
+        .collect();
+    let mut lifetime_rewriter = LifetimeRewriter::with_preserved_lifetimes(
+        UniformLifetimeGenerator::new(lifetime.clone()),
+        trait_lifetimes,
+    );
     visit_type_mut(&mut lifetime_rewriter, &mut ty);
     ty
 }
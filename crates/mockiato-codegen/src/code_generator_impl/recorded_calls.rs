@@ -0,0 +1,64 @@
+use super::constant::recorded_calls_method_ident;
+use super::util::{doc_attribute, trait_method_anchor};
+use super::verify::{method_idents, supertrait_mock_field_idents};
+use crate::parse::mockable_attr::SupertraitMock;
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `collect_recorded_calls` method of the `Mock` trait,
+/// which collects the recorded calls of all methods (including the ones of supertrait mocks).
+pub(super) fn generate_collect_recorded_calls_method(
+    trait_decl: &TraitDecl,
+    supertrait_mocks: &[SupertraitMock],
+) -> TokenStream {
+    let method_idents = method_idents(trait_decl);
+    let supertrait_mock_field_idents = supertrait_mock_field_idents(supertrait_mocks);
+
+    quote! {
+        // The calls are unused if the trait has neither methods nor supertrait mocks.
+        #[allow(unused_variables)]
+        fn collect_recorded_calls(&self, calls: &mut std::vec::Vec<mockiato::RecordedCall>) {
+            #( calls.extend(self.#method_idents.recorded_calls()); )*
+            #(
+                mockiato::internal::Mock::collect_recorded_calls(
+                    &self.#supertrait_mock_field_idents,
+                    calls,
+                );
+            )*
+        }
+    }
+}
+
+/// Generates a `calls_to_<method_name>` associated function for every method,
+/// which returns the recorded calls of that method.
+/// It doesn't take `self`, so that it can't shadow a method of the mocked trait.
+pub(super) fn generate_recorded_calls_methods(trait_decl: &TraitDecl) -> TokenStream {
+    let visibility = &trait_decl.visibility;
+
+    trait_decl
+        .methods
+        .iter()
+        .filter(|method_decl| method_decl.has_self_arg())
+        .map(|method_decl| {
+            let method_ident = &method_decl.ident;
+            let recorded_calls_method_ident = recorded_calls_method_ident(method_ident);
+
+            let documentation = doc_attribute(format!(
+                "Returns the calls made to [`{0}::{1}`], in the order in which they were made.
+
+[`{0}::{1}`]: ./trait.{0}.html#{2}",
+                trait_decl.ident,
+                method_ident,
+                trait_method_anchor(method_decl),
+            ));
+
+            quote! {
+                #documentation
+                #visibility fn #recorded_calls_method_ident(mock: &Self) -> std::vec::Vec<mockiato::RecordedCall> {
+                    mock.#method_ident.recorded_calls()
+                }
+            }
+        })
+        .collect()
+}
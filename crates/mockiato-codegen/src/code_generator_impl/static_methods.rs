@@ -0,0 +1,415 @@
+use super::associated_types::generics_with_associated_types;
+use super::constant::{mock_lifetime, static_guard_ident, static_lifetime, static_methods_ident};
+use super::debug_impl::{generate_debug_impl, DebugImplField};
+use super::mock_struct::{
+    generate_expect_method, generate_expect_method_calls_in_order_method, rewrite_lifetimes,
+};
+use super::util::{doc_attribute, lifetime_to_generic_param, thread_safety_marker};
+use super::visibility::raise_visibility_by_one_level;
+use super::{GenerateMockParameters, MethodDeclMetadata};
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::visit::{self, Visit};
+use syn::visit_mut::{visit_type_mut, VisitMut};
+use syn::{parse_quote, Generics, LitStr, Type, TypePath};
+
+/// Associated functions (i.e. functions without a `self` receiver) can't access a mock instance.
+/// Their expectations are stored in a guard that registers them in a thread-local registry instead.
+pub(super) fn has_associated_functions(trait_decl: &TraitDecl) -> bool {
+    trait_decl
+        .methods
+        .iter()
+        .any(|method_decl| !method_decl.has_self_arg())
+}
+
+/// Generates the associated function on the mock that creates the guard.
+pub(super) fn generate_static_expectations_method(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    if !has_associated_functions(trait_decl) {
+        return TokenStream::new();
+    }
+
+    let visibility = &trait_decl.visibility;
+    let guard_ident = static_guard_ident(&parameters.mock_struct_ident);
+    let generics = static_generics(trait_decl);
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    let documentation = doc_attribute(format!(
+        "Creates a guard for configuring the expected calls to the associated functions of \
+         [`{0}`].
+
+The expectations are active on the current thread until the guard goes out of scope.
+Only one guard can be active per mock type and thread.
+Calls from other threads don't see these expectations, even for mocks generated using \
+         `#[mockable(sync)]`.
+
+[`{0}`]: ./trait.{0}.html",
+        trait_decl.ident
+    ));
+
+    quote! {
+        #documentation
+        #visibility fn static_expectations() -> #guard_ident #ty_generics #where_clause {
+            #guard_ident::new()
+        }
+    }
+}
+
+/// Generates the guard holding the expectations for the associated functions.
+pub(super) fn generate_static_guard(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    if !has_associated_functions(trait_decl) {
+        return TokenStream::new();
+    }
+
+    let mock_struct_ident = &parameters.mock_struct_ident;
+    let mod_ident = &parameters.mod_ident;
+    let guard_ident = static_guard_ident(mock_struct_ident);
+    let static_methods_ident = static_methods_ident();
+    let static_lifetime = static_lifetime();
+
+    let initializer_fields: TokenStream = static_methods(parameters)
+        .map(|method| {
+            let method_ident = &method.method_decl.ident;
+            let name = LitStr::new(
+                &format!("{}::{}", mock_struct_ident, method_ident),
+                Span::call_site(),
+            );
+
+            quote! { #method_ident: mockiato::internal::Method::new(#name), }
+        })
+        .collect();
+
+    let expect_methods: TokenStream = static_methods(parameters)
+        .map(|method| {
+            let method_ident = &method.method_decl.ident;
+            let method = &with_resolved_self_type(method, parameters);
+
+            generate_expect_method(
+                method,
+                trait_decl,
+                parameters,
+                &static_lifetime,
+                |matcher| {
+                    quote! {
+                        self.expectations.add_expected_call(
+                            |statics| &mut statics.#method_ident,
+                            #matcher
+                        )
+                    }
+                },
+            )
+        })
+        .collect();
+
+    let expect_method_calls_in_order_methods: TokenStream = static_methods(parameters)
+        .map(|method| {
+            generate_expect_method_calls_in_order_method(
+                trait_decl,
+                &method.method_decl,
+                &quote! { self.expectations.statics_mut() },
+            )
+        })
+        .collect();
+
+    let verify_calls: TokenStream = static_methods(parameters)
+        .map(|method| {
+            let method_ident = &method.method_decl.ident;
+            quote! { statics.#method_ident.verify_unwrap(); }
+        })
+        .collect();
+
+    let generics = static_generics(trait_decl);
+    let debug_impl_fields = static_methods(parameters).map(|method| {
+        let ident = &method.method_decl.ident;
+        DebugImplField {
+            ident,
+            expression: quote! { self.expectations.statics().#ident },
+        }
+    });
+    let debug_impl = generate_debug_impl(debug_impl_fields, &guard_ident, &generics);
+
+    let visibility = &trait_decl.visibility;
+    let mock_name = LitStr::new(&mock_struct_ident.to_string(), Span::call_site());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let documentation = doc_attribute(format!(
+        "Holds the expected calls to the associated functions of [`{0}`].
+
+The expected calls are verified when the guard goes out of scope.
+A guard can be created using [`{1}::static_expectations`].
+
+[`{0}`]: ./trait.{0}.html
+[`{1}::static_expectations`]: ./struct.{1}.html#method.static_expectations",
+        trait_decl.ident, mock_struct_ident
+    ));
+
+    quote! {
+        #documentation
+        #visibility struct #guard_ident #ty_generics #where_clause {
+            expectations: mockiato::internal::StaticExpectations<
+                #mod_ident::#static_methods_ident #ty_generics
+            >,
+        }
+
+        impl #impl_generics #guard_ident #ty_generics #where_clause {
+            fn new() -> Self {
+                Self {
+                    expectations: mockiato::internal::StaticExpectations::new(
+                        #mock_name,
+                        #mod_ident::#static_methods_ident {
+                            #initializer_fields
+                            phantom_data: std::marker::PhantomData,
+                        },
+                    ),
+                }
+            }
+
+            #expect_methods
+
+            #expect_method_calls_in_order_methods
+        }
+
+        #debug_impl
+
+        impl #impl_generics Drop for #guard_ident #ty_generics #where_clause {
+            fn drop(&mut self) {
+                if !std::thread::panicking() {
+                    let statics = self.expectations.statics();
+                    #verify_calls
+                }
+            }
+        }
+    }
+}
+
+/// Generates the struct holding a method for every associated function.
+/// This struct is part of the internal sub-mod.
+pub(super) fn generate_static_methods_struct(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    if !has_associated_functions(trait_decl) {
+        return TokenStream::new();
+    }
+
+    let visibility = raise_visibility_by_one_level(&trait_decl.visibility);
+    let static_methods_ident = static_methods_ident();
+
+    let method_fields: TokenStream = static_methods(parameters)
+        .map(|method| {
+            let method_ident = &method.method_decl.ident;
+            let method_type = static_method_type(method, parameters);
+            quote! { #visibility #method_ident: #method_type, }
+        })
+        .collect();
+
+    let generics = static_generics(trait_decl);
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let type_params = generics.type_params().map(|param| &param.ident);
+
+    quote! {
+        #[doc(hidden)]
+        #visibility struct #static_methods_ident #ty_generics #where_clause {
+            #method_fields
+            #visibility phantom_data: std::marker::PhantomData<(#(fn() -> #type_params,)*)>,
+        }
+    }
+}
+
+/// Generates the expression calling the expectations of an associated function in the trait impl.
+pub(super) fn generate_associated_function_call(
+    MethodDeclMetadata {
+        method_decl,
+        arguments_struct_ident,
+        ..
+    }: &MethodDeclMetadata,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+    call_unwrap: &TokenStream,
+) -> TokenStream {
+    let mod_ident = &parameters.mod_ident;
+    let static_methods_ident = static_methods_ident();
+    let method_ident = &method_decl.ident;
+    let generics = static_generics(trait_decl);
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let name = LitStr::new(
+        &format!("{}::{}", parameters.mock_struct_ident, method_ident),
+        Span::call_site(),
+    );
+
+    let arguments_struct_fields: TokenStream = method_decl
+        .inputs
+        .args
+        .iter()
+        .map(|argument| {
+            let ident = &argument.ident;
+            quote! { #ident, }
+        })
+        .collect();
+
+    quote! {
+        {
+            let statics = mockiato::internal::StaticExpectations::<
+                #mod_ident::#static_methods_ident #ty_generics
+            >::current(#name);
+            let statics = statics.borrow();
+
+            statics.#method_ident.#call_unwrap(
+                #mod_ident::#arguments_struct_ident {
+                    #arguments_struct_fields
+                    phantom_data: std::marker::PhantomData,
+                }
+            )
+        }
+    }
+}
+
+/// The generics of the trait impl. Its associated functions look up their expectations
+/// in the thread-local registry, which requires all generic types to be `'static`.
+/// Associated functions returning `Self` return mocks that are stored in the registry,
+/// so the mock lifetime needs to be `'static` as well.
+pub(super) fn trait_impl_generics(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> Generics {
+    let mut generics = parameters.generics.clone();
+
+    if !has_associated_functions(trait_decl) {
+        return generics;
+    }
+
+    add_static_bounds_to_type_params(&mut generics);
+
+    if static_methods(parameters).any(returns_self) {
+        let mock_lifetime = mock_lifetime();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#mock_lifetime: 'static));
+    }
+
+    generics
+}
+
+/// The generics of the guard. The expectations are stored in a thread-local registry,
+/// which requires all generic types to be `'static`.
+fn static_generics(trait_decl: &TraitDecl) -> Generics {
+    let mut generics = generics_with_associated_types(trait_decl);
+    add_static_bounds_to_type_params(&mut generics);
+    generics
+}
+
+fn add_static_bounds_to_type_params(generics: &mut Generics) {
+    let type_params: Vec<_> = generics
+        .type_params()
+        .map(|type_param| type_param.ident.clone())
+        .collect();
+
+    for ident in type_params {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#ident: 'static));
+    }
+}
+
+fn static_methods(
+    parameters: &GenerateMockParameters,
+) -> impl Iterator<Item = &MethodDeclMetadata> {
+    parameters
+        .methods
+        .iter()
+        .filter(|method| !method.method_decl.has_self_arg())
+}
+
+fn static_method_type(method: &MethodDeclMetadata, parameters: &GenerateMockParameters) -> Type {
+    let MethodDeclMetadata {
+        arguments_matcher_struct_ident,
+        generics,
+        return_type,
+        ..
+    } = &with_resolved_self_type(method, parameters);
+    let static_lifetime = static_lifetime();
+    let return_type = rewrite_lifetimes(return_type, generics, &static_lifetime);
+    let thread_safety_marker = thread_safety_marker(parameters.sync);
+
+    let mut generics = generics.clone();
+    generics
+        .params
+        .push(lifetime_to_generic_param(static_lifetime.clone()));
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    parse_quote! {
+        mockiato::internal::Method<
+            #static_lifetime,
+            #arguments_matcher_struct_ident #ty_generics,
+            #return_type,
+            #thread_safety_marker
+        >
+    }
+}
+
+fn returns_self(method: &MethodDeclMetadata) -> bool {
+    let mut self_type_finder = SelfTypeFinder::default();
+    self_type_finder.visit_type(&method.return_type);
+    self_type_finder.found
+}
+
+/// Replaces `Self` (e.g. in the return type of a constructor) with the mock type,
+/// since the expectations are stored outside of the trait impl.
+fn with_resolved_self_type(
+    method: &MethodDeclMetadata,
+    parameters: &GenerateMockParameters,
+) -> MethodDeclMetadata {
+    let mut method = method.clone();
+    SelfTypeRewriter {
+        mock_type: static_mock_type(parameters),
+    }
+    .visit_type_mut(&mut method.return_type);
+    method
+}
+
+/// The type of the mock with a `'static` mock lifetime.
+fn static_mock_type(parameters: &GenerateMockParameters) -> Type {
+    let mock_struct_ident = &parameters.mock_struct_ident;
+    let type_params = parameters.generics.type_params().map(|param| &param.ident);
+
+    parse_quote!(#mock_struct_ident<'static, #(#type_params),*>)
+}
+
+#[derive(Default)]
+struct SelfTypeFinder {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for SelfTypeFinder {
+    fn visit_type_path(&mut self, type_path: &'ast TypePath) {
+        if type_path.qself.is_none() && type_path.path.is_ident("Self") {
+            self.found = true;
+        } else {
+            visit::visit_type_path(self, type_path);
+        }
+    }
+}
+
+struct SelfTypeRewriter {
+    mock_type: Type,
+}
+
+impl VisitMut for SelfTypeRewriter {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        match ty {
+            Type::Path(TypePath { qself: None, path }) if path.is_ident("Self") => {
+                *ty = self.mock_type.clone();
+            }
+            _ => visit_type_mut(self, ty),
+        }
+    }
+}
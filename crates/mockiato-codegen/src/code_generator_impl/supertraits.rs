@@ -0,0 +1,229 @@
+use super::constant::{forward_to_mock_macro_ident, mock_lifetime, supertrait_mock_field_ident};
+use super::util::doc_attribute;
+use super::GenerateMockParameters;
+use crate::parse::method_decl::MethodDecl;
+use crate::parse::method_inputs::MethodSelfArg;
+use crate::parse::mockable_attr::SupertraitMock;
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{LitStr, Token};
+
+/// Generates a macro that implements the trait for another mock
+/// by forwarding all calls to a field holding this mock.
+///
+/// Proc macros can't look up the declaration of a supertrait,
+/// which is why the mock of the supertrait provides the implementation.
+pub(super) fn generate_forward_to_mock_macro(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let macro_ident = forward_to_mock_macro_ident(&parameters.mock_struct_ident);
+
+    let body = match unsupported_forwarding_reason(trait_decl) {
+        Some(reason) => {
+            let message = LitStr::new(
+                &format!(
+                    "{} can't be used as a supertrait mock: {}",
+                    parameters.mock_struct_ident, reason
+                ),
+                parameters.mock_struct_ident.span(),
+            );
+
+            quote! { compile_error!(#message); }
+        }
+        None => generate_forwarding_impl(trait_decl, parameters),
+    };
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        macro_rules! #macro_ident {
+            (
+                impl [$($impl_generics:tt)*] for [$($self_ty:tt)*] [$($where_clause:tt)*]
+                => $field:ident: [$($mock_ty:tt)*]
+            ) => {
+                #body
+            };
+        }
+    }
+}
+
+/// Generates the implementations of the supertraits by invoking
+/// the macros generated for the supertrait mocks.
+pub(super) fn generate_supertrait_impls(
+    parameters: &GenerateMockParameters,
+    supertrait_mocks: &[SupertraitMock],
+) -> TokenStream {
+    let mock_struct_ident = &parameters.mock_struct_ident;
+    let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
+
+    supertrait_mocks
+        .iter()
+        .map(|supertrait_mock| {
+            let macro_ident = forward_to_mock_macro_ident(&supertrait_mock.mock_struct_ident);
+            let field_ident = supertrait_mock_field_ident(&supertrait_mock.mock_struct_ident);
+            let field_type = supertrait_mock_type(supertrait_mock);
+
+            quote! {
+                #macro_ident! {
+                    impl [#impl_generics] for [#mock_struct_ident #ty_generics] [#where_clause]
+                    => #field_ident: [#field_type]
+                }
+            }
+        })
+        .collect()
+}
+
+/// Generates the fields holding the supertrait mocks.
+pub(super) fn generate_supertrait_mock_fields(supertrait_mocks: &[SupertraitMock]) -> TokenStream {
+    supertrait_mocks
+        .iter()
+        .map(|supertrait_mock| {
+            let field_ident = supertrait_mock_field_ident(&supertrait_mock.mock_struct_ident);
+            let field_type = supertrait_mock_type(supertrait_mock);
+
+            quote! { #field_ident: #field_type, }
+        })
+        .collect()
+}
+
+pub(super) fn generate_supertrait_mock_initializer_fields(
+    supertrait_mocks: &[SupertraitMock],
+) -> TokenStream {
+    supertrait_mocks
+        .iter()
+        .map(|supertrait_mock| {
+            let mock_struct_ident = &supertrait_mock.mock_struct_ident;
+            let field_ident = supertrait_mock_field_ident(mock_struct_ident);
+
+            quote! { #field_ident: #mock_struct_ident::new(), }
+        })
+        .collect()
+}
+
+/// Generates the methods that give access to the supertrait mocks,
+/// so that expectations can be configured on them.
+pub(super) fn generate_supertrait_mock_accessors(
+    trait_decl: &TraitDecl,
+    supertrait_mocks: &[SupertraitMock],
+) -> TokenStream {
+    let visibility = &trait_decl.visibility;
+
+    supertrait_mocks
+        .iter()
+        .map(|supertrait_mock| {
+            let field_ident = supertrait_mock_field_ident(&supertrait_mock.mock_struct_ident);
+            let field_type = supertrait_mock_type(supertrait_mock);
+
+            let documentation = doc_attribute(format!(
+                "Returns the [`{}`] that is used to implement the supertrait.",
+                supertrait_mock.mock_struct_ident
+            ));
+
+            quote! {
+                #documentation
+                #visibility fn #field_ident(&mut self) -> &mut #field_type {
+                    &mut self.#field_ident
+                }
+            }
+        })
+        .collect()
+}
+
+fn supertrait_mock_type(supertrait_mock: &SupertraitMock) -> TokenStream {
+    let mock_struct_ident = &supertrait_mock.mock_struct_ident;
+    let mock_lifetime = mock_lifetime();
+
+    quote! { #mock_struct_ident<#mock_lifetime> }
+}
+
+fn unsupported_forwarding_reason(trait_decl: &TraitDecl) -> Option<&'static str> {
+    if !trait_decl.generics.params.is_empty() {
+        Some("Traits with generic parameters are not supported")
+    } else if !trait_decl.associated_types.is_empty() {
+        Some("Traits with associated types are not supported")
+    } else if trait_decl
+        .methods
+        .iter()
+        .any(|method_decl| !has_self_ref_arg(method_decl))
+    {
+        Some("Only methods that take `self` by reference are supported")
+    } else {
+        None
+    }
+}
+
+fn has_self_ref_arg(method_decl: &MethodDecl) -> bool {
+    match method_decl.inputs.self_arg {
+        Some(MethodSelfArg::Ref(_)) => true,
+        _ => false,
+    }
+}
+
+fn generate_forwarding_impl(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let trait_path = &parameters.trait_path;
+    let unsafety = &trait_decl.unsafety;
+
+    let associated_consts: TokenStream = trait_decl
+        .associated_consts
+        .iter()
+        .map(|associated_const| {
+            let ident = &associated_const.ident;
+            let ty = &associated_const.ty;
+
+            quote! { const #ident: #ty = <$($mock_ty)* as #trait_path>::#ident; }
+        })
+        .collect();
+
+    let methods: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(generate_forwarding_method)
+        .collect();
+
+    quote! {
+        #unsafety impl $($impl_generics)* #trait_path for $($self_ty)* $($where_clause)* {
+            #associated_consts
+
+            #methods
+        }
+    }
+}
+
+fn generate_forwarding_method(method_decl: &MethodDecl) -> TokenStream {
+    let MethodDecl {
+        ident,
+        unsafety,
+        asyncness,
+        generics,
+        inputs,
+        output,
+        ..
+    } = method_decl;
+
+    let self_arg = &inputs.self_arg;
+    let arguments: Punctuated<_, Token![,]> = inputs.args.iter().collect();
+    let argument_idents: Punctuated<_, Token![,]> =
+        inputs.args.iter().map(|argument| &argument.ident).collect();
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let type_params: Vec<_> = generics.type_params().map(|param| &param.ident).collect();
+    let turbofish = if type_params.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! { ::<#(#type_params),*> }
+    };
+
+    let await_call = asyncness.map(|_| quote! { .await });
+
+    quote! {
+        #asyncness #unsafety fn #ident #impl_generics(#self_arg, #arguments) #output #where_clause {
+            self.$field.#ident #turbofish(#argument_idents) #await_call
+        }
+    }
+}
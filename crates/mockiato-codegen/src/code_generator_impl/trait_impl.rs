@@ -1,15 +1,24 @@
+use super::associated_types::generate_associated_types;
+use super::constant::static_lifetime;
+use super::default_impls::generate_call_with_default_impl;
+use super::generics::type_params_as_tuple;
+use super::mock_struct::rewrite_lifetimes;
+use super::static_methods::{generate_associated_function_call, trait_impl_generics};
+use super::util::lifetime_to_generic_param;
 use super::GenerateMockParameters;
 use super::MethodDeclMetadata;
 use crate::parse::method_decl::MethodDecl;
+use crate::parse::mockable_attr::ConstantValue;
 use crate::parse::trait_decl::TraitDecl;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::punctuated::Punctuated;
-use syn::{Ident, Token};
+use syn::Token;
 
 pub(crate) fn generate_trait_impl(
     trait_decl: &TraitDecl,
     parameters: &'_ GenerateMockParameters,
+    constants: &[ConstantValue],
 ) -> TokenStream {
     let trait_path = &parameters.trait_path;
     let unsafety = &trait_decl.unsafety;
@@ -18,36 +27,68 @@ pub(crate) fn generate_trait_impl(
     let method_impls: TokenStream = parameters
         .methods
         .iter()
-        .map(|method| generate_method_impl(method, &parameters.mod_ident))
+        .map(|method| generate_method_impl(method, trait_decl, parameters))
         .collect();
 
-    let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
+    let associated_types = generate_associated_types(trait_decl);
+    let associated_consts = generate_associated_consts(trait_decl, constants);
+
+    let generics = trait_impl_generics(trait_decl, parameters);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let (_, trait_ty_generics, _) = trait_decl.generics.split_for_impl();
 
     quote! {
         #unsafety impl #impl_generics #trait_path #trait_ty_generics for #mock_struct_ident #ty_generics #where_clause {
+            #associated_types
+
+            #associated_consts
+
             #method_impls
         }
     }
 }
 
+/// Generates the associated constants that have a value specified in the attribute.
+/// Constants that are not specified have a default value in the trait.
+/// Values for constants that the trait doesn't declare are rejected by the controller.
+fn generate_associated_consts(trait_decl: &TraitDecl, constants: &[ConstantValue]) -> TokenStream {
+    trait_decl
+        .associated_consts
+        .iter()
+        .filter_map(|associated_const| {
+            let ident = &associated_const.ident;
+            let ty = &associated_const.ty;
+            let constant = constants.iter().find(|constant| &constant.ident == ident)?;
+            let value = &constant.value;
+
+            Some(quote! { const #ident: #ty = #value; })
+        })
+        .collect()
+}
+
 fn generate_method_impl(
-    MethodDeclMetadata {
+    method: &MethodDeclMetadata,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let MethodDeclMetadata {
         arguments_struct_ident,
+        arguments_matcher_struct_ident,
+        generics: arguments_struct_generics,
         method_decl:
             MethodDecl {
                 ident,
                 unsafety,
+                asyncness,
                 generics,
                 inputs,
                 output,
                 ..
             },
         ..
-    }: &MethodDeclMetadata,
-    mod_ident: &Ident,
-) -> TokenStream {
-    let self_arg = &inputs.self_arg;
+    } = method;
+    let mod_ident = &parameters.mod_ident;
+    let self_arg = inputs.self_arg.iter().map(|self_arg| quote! { #self_arg, });
     let arguments: Punctuated<_, Token![,]> = inputs.args.iter().collect();
 
     let (impl_generics, _, where_clause) = generics.split_for_impl();
@@ -61,14 +102,60 @@ fn generate_method_impl(
         })
         .collect();
 
-    quote! {
-        #unsafety fn #ident#impl_generics(#self_arg, #arguments) #output #where_clause {
-            self.#ident.call_unwrap(
+    // Methods with a default implementation get the arguments back if the default should be called.
+    // Nice mocks return default values for unexpected calls.
+    let call_unwrap = match (method.method_decl.default_impl.is_some(), parameters.nice) {
+        (true, false) => quote! { call_or_default_unwrap },
+        (true, true) => quote! { lenient_call_or_default_unwrap },
+        (false, false) => quote! { call_unwrap },
+        (false, true) => quote! { lenient_call_unwrap },
+    };
+
+    let call_unwrap = match type_params_as_tuple(generics) {
+        Some(type_params) => {
+            // The expectations of generic methods are stored with `'static` lifetimes.
+            let mut arguments_matcher_generics = arguments_struct_generics.clone();
+            arguments_matcher_generics
+                .params
+                .push(lifetime_to_generic_param(static_lifetime()));
+            let (_, ty_generics, _) = arguments_matcher_generics.split_for_impl();
+            let return_type = rewrite_lifetimes(
+                &method.return_type,
+                arguments_struct_generics,
+                &static_lifetime(),
+            );
+
+            quote! {
+                #call_unwrap::<#type_params, #mod_ident::#arguments_matcher_struct_ident #ty_generics, #return_type>
+            }
+        }
+        None => call_unwrap,
+    };
+
+    let call = if inputs.self_arg.is_some() {
+        quote! {
+            self.#ident.#call_unwrap(
                 #mod_ident::#arguments_struct_ident {
                     #arguments_struct_fields
                     phantom_data: std::marker::PhantomData,
                 }
             )
         }
+    } else {
+        generate_associated_function_call(method, trait_decl, parameters, &call_unwrap)
+    };
+
+    let body = if method.method_decl.default_impl.is_some() {
+        generate_call_with_default_impl(method, trait_decl, parameters, call)
+    } else {
+        call
+    };
+
+    // The body of an async method only runs once the returned future is polled,
+    // which means that the return value is also generated inside the future.
+    quote! {
+        #asyncness #unsafety fn #ident#impl_generics(#(#self_arg)* #arguments) #output #where_clause {
+            #body
+        }
     }
 }
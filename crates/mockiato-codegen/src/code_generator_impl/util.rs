@@ -1,5 +1,7 @@
-use proc_macro2::Span;
-use syn::{parse_quote, Attribute, GenericParam, Ident, Lifetime, LifetimeDef, LitStr};
+use crate::parse::method_decl::MethodDecl;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, Attribute, GenericParam, Ident, Lifetime, LifetimeDef, LitStr, Path};
 
 pub(super) fn doc_attribute(content: String) -> Attribute {
     let string_literal = LitStr::new(&content, Span::call_site());
@@ -9,10 +11,39 @@ pub(super) fn doc_attribute(content: String) -> Attribute {
     }
 }
 
+/// The anchor of a method in the documentation of its trait.
+/// Rustdoc distinguishes between required methods and methods with a default implementation.
+pub(super) fn trait_method_anchor(method_decl: &MethodDecl) -> String {
+    let kind = match method_decl.default_impl {
+        Some(_) => "method",
+        None => "tymethod",
+    };
+
+    format!("{}.{}", kind, method_decl.ident)
+}
+
 pub(super) fn ident_to_string_literal(ident: &Ident) -> LitStr {
     LitStr::new(&ident.to_string(), ident.span())
 }
 
+/// The marker specifying whether the expectations of the mock can be shared between threads.
+pub(super) fn thread_safety_marker(sync: bool) -> Path {
+    if sync {
+        parse_quote!(mockiato::internal::ThreadSafe)
+    } else {
+        parse_quote!(mockiato::internal::SingleThreaded)
+    }
+}
+
+/// Additional bounds for the values stored in the expectations of a mock that is `Send` and `Sync`.
+pub(super) fn thread_safety_bounds(sync: bool) -> TokenStream {
+    if sync {
+        quote! { + Send + Sync }
+    } else {
+        TokenStream::new()
+    }
+}
+
 pub(super) fn lifetime_to_generic_param(lifetime: Lifetime) -> GenericParam {
     GenericParam::Lifetime(LifetimeDef::new(lifetime))
 }
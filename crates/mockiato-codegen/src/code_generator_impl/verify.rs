@@ -0,0 +1,46 @@
+use super::constant::supertrait_mock_field_ident;
+use crate::parse::mockable_attr::SupertraitMock;
+use crate::parse::trait_decl::TraitDecl;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Generates the `collect_unsatisfied_expectations` method of the `Mock` trait,
+/// which reports the unsatisfied expected calls of all methods instead of panicking.
+pub(super) fn generate_collect_unsatisfied_expectations_method(
+    trait_decl: &TraitDecl,
+    supertrait_mocks: &[SupertraitMock],
+) -> TokenStream {
+    let method_idents = method_idents(trait_decl);
+    let supertrait_mock_field_idents = supertrait_mock_field_idents(supertrait_mocks);
+
+    quote! {
+        // The report is unused if the trait has neither methods nor supertrait mocks.
+        #[allow(unused_variables)]
+        fn collect_unsatisfied_expectations(&self, report: &mut mockiato::MockVerificationReport) {
+            #( self.#method_idents.collect_unsatisfied_expectations(report); )*
+            #(
+                mockiato::internal::Mock::collect_unsatisfied_expectations(
+                    &self.#supertrait_mock_field_idents,
+                    report,
+                );
+            )*
+        }
+    }
+}
+
+pub(super) fn method_idents(trait_decl: &TraitDecl) -> Vec<&Ident> {
+    trait_decl
+        .methods
+        .iter()
+        .filter(|method_decl| method_decl.has_self_arg())
+        .map(|method_decl| &method_decl.ident)
+        .collect()
+}
+
+pub(super) fn supertrait_mock_field_idents(supertrait_mocks: &[SupertraitMock]) -> Vec<Ident> {
+    supertrait_mocks
+        .iter()
+        .map(|supertrait_mock| supertrait_mock_field_ident(&supertrait_mock.mock_struct_ident))
+        .collect()
+}
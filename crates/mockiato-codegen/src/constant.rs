@@ -2,4 +2,8 @@ pub(crate) const ATTR_NAME: &str = "mockable";
 pub(crate) const STATIC_REFERENCES_ATTR_PARAM_NAME: &str = "static_references";
 pub(crate) const MOCK_STRUCT_NAME_ATTR_PARAM_NAME: &str = "name";
 pub(crate) const REMOTE_ATTR_PARAM_NAME: &str = "remote";
+pub(crate) const CONSTANTS_ATTR_PARAM_NAME: &str = "constants";
+pub(crate) const SUPERTRAIT_MOCKS_ATTR_PARAM_NAME: &str = "supertrait_mocks";
+pub(crate) const SYNC_ATTR_PARAM_NAME: &str = "sync";
+pub(crate) const NICE_ATTR_PARAM_NAME: &str = "nice";
 pub(crate) const CREATE_ISSUE_LINK: &str = "https://github.com/myelin-ai/mockiato/issues/new";
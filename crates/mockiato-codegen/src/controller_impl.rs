@@ -1,13 +1,17 @@
 use crate::code_generator::{self, CodeGenerator};
+use crate::constant::{ATTR_NAME, CONSTANTS_ATTR_PARAM_NAME};
 use crate::diagnostic::DiagnosticBuilder;
-use crate::parse::mockable_attr::{MockableAttr, MockableAttrParser, RemoteTraitPath};
-use crate::parse::trait_decl::TraitDeclParser;
+use crate::parse::mockable_attr::{
+    ConstantValue, MockableAttr, MockableAttrParser, RemoteTraitPath, SupertraitMock,
+};
+use crate::parse::trait_decl::{AssociatedConstDecl, TraitDecl, TraitDeclParser};
 use crate::result::{Error, Result};
+use crate::syn_ext::PathExt;
 use crate::Controller;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{AttributeArgs, Item, ItemTrait};
+use syn::{AttributeArgs, Item, ItemTrait, Path, TraitBound, TypeParamBound};
 
 #[derive(Debug)]
 pub(crate) struct ControllerImpl {
@@ -39,6 +43,9 @@ impl Controller for ControllerImpl {
             .parse(item_trait.clone())
             .map_err(add_note_to_error)?;
 
+        validate_constant_values(&trait_decl, &mockable_attr.constants)?;
+        validate_supertrait_mocks(&trait_decl, &mockable_attr.supertrait_mocks)?;
+
         let emit_item_trait = match mockable_attr.remote_trait_path {
             Some(_) => None,
             None => Some(item_trait),
@@ -59,6 +66,10 @@ fn generate_options_from_mockable_attr(
         remote_trait_path,
         name,
         force_static_lifetimes,
+        constants,
+        supertrait_mocks,
+        sync,
+        nice,
     }: MockableAttr,
 ) -> code_generator::GenerateOptions {
     let custom_trait_path = match remote_trait_path {
@@ -69,9 +80,142 @@ fn generate_options_from_mockable_attr(
         custom_struct_ident: name,
         force_static_lifetimes,
         custom_trait_path,
+        constants,
+        supertrait_mocks,
+        sync,
+        nice,
+    }
+}
+
+fn validate_constant_values(trait_decl: &TraitDecl, constants: &[ConstantValue]) -> Result<()> {
+    let missing_values = trait_decl
+        .associated_consts
+        .iter()
+        .filter(|associated_const| !associated_const.has_default)
+        .filter(|associated_const| {
+            !constants
+                .iter()
+                .any(|constant| constant.ident == associated_const.ident)
+        })
+        .map(|associated_const| add_note_to_error(missing_constant_value_error(associated_const)));
+
+    let unknown_constants = constants
+        .iter()
+        .filter(|constant| {
+            !trait_decl
+                .associated_consts
+                .iter()
+                .any(|associated_const| associated_const.ident == constant.ident)
+        })
+        .map(|constant| unknown_constant_error(constant, trait_decl));
+
+    let errors: Vec<_> = missing_values.chain(unknown_constants).collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into_iter().collect())
+    }
+}
+
+fn validate_supertrait_mocks(
+    trait_decl: &TraitDecl,
+    supertrait_mocks: &[SupertraitMock],
+) -> Result<()> {
+    let errors: Vec<_> = supertrait_mocks
+        .iter()
+        .filter_map(|supertrait_mock| {
+            let supertrait = trait_decl
+                .supertraits
+                .iter()
+                .filter_map(|bound| match bound {
+                    TypeParamBound::Trait(TraitBound { path, .. }) => Some(path),
+                    TypeParamBound::Lifetime(_) => None,
+                })
+                .find(|path| path.eq_ignoring_arguments(&supertrait_mock.supertrait));
+
+            match supertrait {
+                None => Some(unknown_supertrait_error(&supertrait_mock.supertrait)),
+                Some(path) if path.has_arguments() => {
+                    Some(add_note_to_error(supertrait_with_arguments_error(path)))
+                }
+                Some(_) => None,
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into_iter().collect())
     }
 }
 
+/// Methods of the trait can't be called on the mock if the mock has
+/// an inherent method with the same name, as the inherent method takes precedence.
+fn unknown_supertrait_error(supertrait: &Path) -> Error {
+    let supertrait_name = supertrait
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    let error_message = format!("The trait has no supertrait named `{}`", supertrait_name);
+    DiagnosticBuilder::error(supertrait.span(), error_message)
+        .build()
+        .into()
+}
+
+fn supertrait_with_arguments_error(supertrait: &Path) -> Error {
+    DiagnosticBuilder::error(
+        supertrait.span(),
+        "Supertrait mocks are not supported for supertraits with generic arguments",
+    )
+    .build()
+    .into()
+}
+
+fn missing_constant_value_error(associated_const: &AssociatedConstDecl) -> Error {
+    let error_message = format!(
+        "No value was specified for the associated constant `{}`",
+        associated_const.ident
+    );
+    let help_message = format!(
+        "Specify a value using #[{attr}({param}({ident} = ...))]",
+        attr = ATTR_NAME,
+        param = CONSTANTS_ATTR_PARAM_NAME,
+        ident = associated_const.ident
+    );
+    DiagnosticBuilder::error(associated_const.span, error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
+fn unknown_constant_error(constant: &ConstantValue, trait_decl: &TraitDecl) -> Error {
+    let error_message = format!(
+        "The trait has no associated constant named `{}`",
+        constant.ident
+    );
+    let help_message = if trait_decl.associated_consts.is_empty() {
+        String::from("The trait doesn't declare any associated constants")
+    } else {
+        let associated_const_names: Vec<_> = trait_decl
+            .associated_consts
+            .iter()
+            .map(|associated_const| format!("`{}`", associated_const.ident))
+            .collect();
+        format!(
+            "The associated constants of the trait are {}",
+            associated_const_names.join(", ")
+        )
+    };
+    DiagnosticBuilder::error(constant.ident.span(), error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
 fn extract_item_trait(item: Item) -> Result<ItemTrait> {
     match item {
         Item::Trait(item_trait) => Ok(item_trait),
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 use std::fmt::Debug;
 
 use proc_macro2::Span;
-use syn::{Attribute, Generics, Ident, ReturnType, Token, TraitItem};
+use syn::{Attribute, Block, Generics, Ident, ReturnType, Signature, Token, TraitItem};
 
 use crate::parse::method_inputs::MethodInputs;
 use crate::result::Result;
@@ -16,6 +16,8 @@ pub(crate) struct MethodDecl {
     pub(crate) attrs: Vec<Attribute>,
     /// Whether this method is unsafe or not
     pub(crate) unsafety: Option<Token![unsafe]>,
+    /// Whether this method is async or not
+    pub(crate) asyncness: Option<Token![async]>,
     /// The name of this method. (E.g. `greet`)
     pub(crate) ident: Ident,
     /// The generic type params (including lifetimes)
@@ -26,6 +28,25 @@ pub(crate) struct MethodDecl {
     pub(crate) inputs: MethodInputs,
     /// Return type of this method.
     pub(crate) output: ReturnType,
+    /// The default implementation provided by the trait
+    pub(crate) default_impl: Option<DefaultMethodImpl>,
+}
+
+/// The default implementation of a method.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct DefaultMethodImpl {
+    /// The signature as it is declared in the trait, including the original argument patterns
+    pub(crate) signature: Signature,
+    pub(crate) body: Block,
+}
+
+impl MethodDecl {
+    /// Whether this method takes a `self` receiver.
+    /// Associated functions without a receiver (e.g. constructors) return `false`.
+    pub(crate) fn has_self_arg(&self) -> bool {
+        self.inputs.self_arg.is_some()
+    }
 }
 
 pub(crate) trait MethodDeclParser: Debug {
@@ -4,11 +4,14 @@ use std::collections::HashSet;
 use proc_macro2::Ident;
 use syn::spanned::Spanned;
 use syn::visit::{visit_type, Visit};
-use syn::{GenericParam, Generics, Path, Signature, TraitItem, TraitItemMethod, Type, TypePath};
+use syn::{
+    GenericParam, Generics, Path, PredicateType, Signature, TraitItem, TraitItemMethod, Type,
+    TypeParam, TypeParamBound, TypePath, WherePredicate,
+};
 
 use crate::diagnostic::DiagnosticBuilder;
 use crate::parse::check_option_is_none;
-use crate::parse::method_decl::{MethodDecl, MethodDeclParser};
+use crate::parse::method_decl::{DefaultMethodImpl, MethodDecl, MethodDeclParser};
 use crate::parse::method_inputs::MethodInputsParser;
 use crate::result::{merge_results, Error, Result};
 use crate::syn_ext::PathExt;
@@ -50,11 +53,17 @@ impl MethodDeclParserImpl {
         let TraitItemMethod {
             attrs,
             sig: signature,
+            default,
             ..
         } = method;
 
         validate_usage_of_generic_types(&signature, generic_types_on_trait)?;
 
+        let default_impl = default.map(|body| DefaultMethodImpl {
+            signature: signature.clone(),
+            body,
+        });
+
         let Signature {
             constness,
             unsafety,
@@ -69,16 +78,29 @@ impl MethodDeclParserImpl {
         validate_generic_type_parameters(&generics)?;
 
         check_option_is_none(&constness, span, "`const` methods are not supported")?;
-        check_option_is_none(&asyncness, span, "`async` methods are not supported")?;
+        #[cfg(not(rustc_supports_async_fn_in_trait))]
+        check_option_is_none(
+            &asyncness,
+            span,
+            "`async` methods are only supported on Rust 1.75 or newer",
+        )?;
+
+        let inputs = self.method_inputs_parser.parse(inputs)?;
+
+        if inputs.self_arg.is_none() {
+            validate_associated_function_generics(&generics)?;
+        }
 
         Ok(MethodDecl {
             attrs,
             unsafety,
+            asyncness,
             ident,
             generics,
             span,
-            inputs: self.method_inputs_parser.parse(inputs)?,
+            inputs,
             output,
+            default_impl,
         })
     }
 }
@@ -86,7 +108,7 @@ impl MethodDeclParserImpl {
 fn invalid_trait_item_error(trait_item: &TraitItem) -> Error {
     DiagnosticBuilder::error(
         trait_item.span(),
-        "Traits are only allowed to contain methods",
+        "Traits are only allowed to contain methods, associated types and associated constants",
     )
     .build()
     .into()
@@ -98,14 +120,66 @@ fn validate_generic_type_parameters(generics: &Generics) -> Result<()> {
         .iter()
         .map(|generic_param| match generic_param {
             GenericParam::Lifetime(_) => Ok(()),
-            generic_param => Err(invalid_generic_param(generic_param)),
+            GenericParam::Type(type_param) => validate_type_param(type_param, generics),
+            GenericParam::Const(_) => Err(const_param_error(generic_param)),
+        });
+
+    merge_results(results).map(|_| ())
+}
+
+/// Type parameters need to be `'static`, so that each instantiation
+/// of the method can be told apart using its `TypeId`.
+fn validate_type_param(type_param: &TypeParam, generics: &Generics) -> Result<()> {
+    let is_static_in_where_clause = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates)
+        .any(|predicate| match predicate {
+            WherePredicate::Type(PredicateType {
+                bounded_ty: Type::Path(TypePath { qself: None, path }),
+                bounds,
+                ..
+            }) => path.is_ident(&type_param.ident) && contains_static_lifetime(bounds),
+            _ => false,
         });
 
+    if contains_static_lifetime(&type_param.bounds) || is_static_in_where_clause {
+        Ok(())
+    } else {
+        Err(DiagnosticBuilder::error(
+            type_param.span(),
+            "Generic type parameters on methods must have a `'static` bound",
+        )
+        .build()
+        .into())
+    }
+}
+
+fn contains_static_lifetime<'a>(bounds: impl IntoIterator<Item = &'a TypeParamBound>) -> bool {
+    bounds.into_iter().any(|bound| match bound {
+        TypeParamBound::Lifetime(lifetime) => lifetime.ident == "static",
+        TypeParamBound::Trait(_) => false,
+    })
+}
+
+/// The expectations of associated functions are not type-erased,
+/// which is why they can't have type parameters.
+fn validate_associated_function_generics(generics: &Generics) -> Result<()> {
+    let results = generics.type_params().map(|type_param| -> Result<()> {
+        Err(DiagnosticBuilder::error(
+            type_param.span(),
+            "Generic type parameters are not supported on associated functions without a `self` \
+             parameter",
+        )
+        .build()
+        .into())
+    });
+
     merge_results(results).map(|_| ())
 }
 
-fn invalid_generic_param(generic_param: &GenericParam) -> Error {
-    let error_message = "Only lifetimes are supported as generic parameters on methods";
+fn const_param_error(generic_param: &GenericParam) -> Error {
+    let error_message = "Const generics are not supported on methods";
     DiagnosticBuilder::error(generic_param.span(), error_message)
         .build()
         .into()
@@ -11,7 +11,8 @@ use crate::result::Result;
 #[derive(Clone)]
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
 pub(crate) struct MethodInputs {
-    pub(crate) self_arg: MethodSelfArg,
+    /// `None` for associated functions without a `self` receiver
+    pub(crate) self_arg: Option<MethodSelfArg>,
     pub(crate) args: Vec<MethodArg>,
 }
 
@@ -55,7 +56,8 @@ pub(crate) struct MethodArg {
 }
 
 pub(crate) trait MethodArgParser: Debug {
-    fn parse(&self, arg: FnArg) -> Result<MethodArg>;
+    /// `index` is the position of the argument, not counting the `self` parameter.
+    fn parse(&self, arg: FnArg, index: usize) -> Result<MethodArg>;
 }
 
 impl ToTokens for MethodArg {
@@ -9,7 +9,7 @@ use crate::parse::method_inputs::{
     MethodArg, MethodArgParser, MethodInputs, MethodInputsParser, MethodSelfArg,
     MethodSelfArgParser,
 };
-use crate::result::{merge_results, Error, Result};
+use crate::result::{merge_results, Result};
 
 #[derive(Debug)]
 pub(crate) struct MethodInputsParserImpl {
@@ -31,16 +31,21 @@ impl MethodInputsParserImpl {
 
 impl MethodInputsParser for MethodInputsParserImpl {
     fn parse(&self, inputs: Punctuated<FnArg, Token![,]>) -> Result<MethodInputs> {
-        let span = inputs.span();
-        let mut inputs_iter = inputs.into_iter();
+        let mut inputs_iter = inputs.into_iter().peekable();
 
         let self_arg = inputs_iter
-            .next()
-            .ok_or(())
-            .and_then(|arg| self.method_self_arg_parser.parse(arg))
-            .map_err(|_| first_argument_is_not_self_error(span))?;
+            .peek()
+            .cloned()
+            .and_then(|arg| self.method_self_arg_parser.parse(arg).ok());
 
-        let args = inputs_iter.map(|arg| self.method_arg_parser.parse(arg));
+        // Associated functions without a `self` receiver only have regular arguments.
+        if self_arg.is_some() {
+            inputs_iter.next();
+        }
+
+        let args = inputs_iter
+            .enumerate()
+            .map(|(index, arg)| self.method_arg_parser.parse(arg, index));
 
         Ok(MethodInputs {
             self_arg,
@@ -49,12 +54,6 @@ impl MethodInputsParser for MethodInputsParserImpl {
     }
 }
 
-fn first_argument_is_not_self_error(span: Span) -> Error {
-    let error_message =
-        "The first parameter of a method must be self, so that the trait is object-safe";
-    DiagnosticBuilder::error(span, error_message).build().into()
-}
-
 #[derive(Debug)]
 pub(crate) struct MethodSelfArgParserImpl;
 
@@ -100,7 +99,7 @@ impl MethodArgParserImpl {
 }
 
 impl MethodArgParser for MethodArgParserImpl {
-    fn parse(&self, arg: FnArg) -> Result<MethodArg> {
+    fn parse(&self, arg: FnArg, index: usize) -> Result<MethodArg> {
         let span = arg.span();
 
         match arg {
@@ -110,26 +109,22 @@ impl MethodArgParser for MethodArgParserImpl {
             FnArg::Typed(captured) => {
                 let span = captured.span();
 
-                match *captured.pat {
-                    Pat::Ident(pat_ident) => {
-                        // Subpat is the part behind the @ in a pattern match.
-                        // See: https://docs.rs/syn/0.15.20/syn/struct.PatIdent.html#structfield.subpat
-                        if pat_ident.subpat.is_some() {
-                            panic!("Sub-pattern should not appear within method declaration");
-                        }
-
-                        Ok(MethodArg {
-                            ident: sanitize_method_ident(&pat_ident.ident),
-                            ty: *captured.ty,
-                            span,
-                        })
+                let ident = match *captured.pat {
+                    // Subpat is the part behind the @ in a pattern match.
+                    // See: https://docs.rs/syn/0.15.20/syn/struct.PatIdent.html#structfield.subpat
+                    Pat::Ident(ref pat_ident) if pat_ident.subpat.is_none() => {
+                        sanitize_method_ident(&pat_ident.ident)
                     }
-                    _ => Err(
-                        DiagnosticBuilder::error(span, "Ignored arguments are not supported")
-                            .build()
-                            .into(),
-                    ),
-                }
+                    // Ignored arguments (`_`) and patterns don't have a name
+                    // that could be used for the generated arguments struct.
+                    ref pat => synthesized_argument_ident(index, pat.span()),
+                };
+
+                Ok(MethodArg {
+                    ident,
+                    ty: *captured.ty,
+                    span,
+                })
             }
             _ => Err(
                 DiagnosticBuilder::error(span, "Only captured arguments are supported")
@@ -145,6 +140,15 @@ impl MethodArgParser for MethodArgParserImpl {
     }
 }
 
+/// Generates a name for an argument that is declared using a pattern.
+/// The name starts with an underscore, so that it can't collide with the
+/// name of another argument, which has its leading underscores removed.
+fn synthesized_argument_ident(index: usize, span: Span) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "_arg";
+
+    Ident::new(&format!("{}{}", IDENTIFIER_PREFIX, index), span)
+}
+
 /// Sanitizes a method identifier by removing all leading underscores
 fn sanitize_method_ident(ident: &Ident) -> Ident {
     let ident_string = ident.to_string();
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use syn::{AttributeArgs, Ident, Path};
+use syn::{AttributeArgs, Ident, Lit, Path};
 
 use crate::result::Result;
 
@@ -17,6 +17,34 @@ pub(crate) struct MockableAttr {
     /// Enables mocking of a remote trait.
     /// Example usage: `#[mockable(remote = "io::Write")]`
     pub(crate) remote_trait_path: Option<RemoteTraitPath>,
+    /// Values for the associated constants of the trait.
+    /// Example usage: `#[mockable(constants(NAME = "greeter", MAX_LENGTH = 10))]`
+    pub(crate) constants: Vec<ConstantValue>,
+    /// Mocks that are used to implement the supertraits of the trait.
+    /// Example usage: `#[mockable(supertrait_mocks(Reader = "ReaderMock"))]`
+    pub(crate) supertrait_mocks: Vec<SupertraitMock>,
+    /// Generates a mock that is `Send` and `Sync`.
+    /// Example usage: `#[mockable(sync)]`
+    pub(crate) sync: bool,
+    /// Generates a mock that returns default values for unexpected calls instead of panicking.
+    /// Example usage: `#[mockable(nice)]`
+    pub(crate) nice: bool,
+}
+
+/// A value for an associated constant specified using the `constants` parameter.
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct ConstantValue {
+    pub(crate) ident: Ident,
+    pub(crate) value: Lit,
+}
+
+/// A mock for a supertrait specified using the `supertrait_mocks` parameter.
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct SupertraitMock {
+    /// The supertrait as it appears in the trait's declaration. (E.g. `Reader`)
+    pub(crate) supertrait: Path,
+    /// The name of the supertrait's mock struct. (E.g. `ReaderMock`)
+    pub(crate) mock_struct_ident: Ident,
 }
 
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
@@ -1,13 +1,18 @@
 use proc_macro2::Span;
+use std::collections::HashSet;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{AttributeArgs, Ident, Lit, Meta, MetaNameValue, NestedMeta};
+use syn::{AttributeArgs, Ident, Lit, Meta, MetaList, MetaNameValue, NestedMeta, Token};
 
 use crate::constant::{
-    ATTR_NAME, MOCK_STRUCT_NAME_ATTR_PARAM_NAME, REMOTE_ATTR_PARAM_NAME,
-    STATIC_REFERENCES_ATTR_PARAM_NAME,
+    ATTR_NAME, CONSTANTS_ATTR_PARAM_NAME, MOCK_STRUCT_NAME_ATTR_PARAM_NAME, NICE_ATTR_PARAM_NAME,
+    REMOTE_ATTR_PARAM_NAME, STATIC_REFERENCES_ATTR_PARAM_NAME, SUPERTRAIT_MOCKS_ATTR_PARAM_NAME,
+    SYNC_ATTR_PARAM_NAME,
 };
 use crate::diagnostic::DiagnosticBuilder;
-use crate::parse::mockable_attr::{MockableAttr, MockableAttrParser, RemoteTraitPath};
+use crate::parse::mockable_attr::{
+    ConstantValue, MockableAttr, MockableAttrParser, RemoteTraitPath, SupertraitMock,
+};
 use crate::result::{merge_results, Error, Result};
 
 #[derive(Default, Debug)]
@@ -32,6 +37,14 @@ fn parse_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAt
         parse_static_references_meta_item(mockable_attr, item)
     } else if item.path().is_ident(REMOTE_ATTR_PARAM_NAME) {
         parse_remote_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(CONSTANTS_ATTR_PARAM_NAME) {
+        parse_constants_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(SUPERTRAIT_MOCKS_ATTR_PARAM_NAME) {
+        parse_supertrait_mocks_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(SYNC_ATTR_PARAM_NAME) {
+        parse_sync_meta_item(mockable_attr, item)
+    } else if item.path().is_ident(NICE_ATTR_PARAM_NAME) {
+        parse_nice_meta_item(mockable_attr, item)
     } else {
         Err(attribute_property_not_supported_error(&item))
     }
@@ -56,7 +69,7 @@ fn parse_static_references_meta_item(
     if mockable_attr.force_static_lifetimes {
         Err(static_references_specified_more_than_once_error(&item))
     } else {
-        validate_static_references_property(&item)?;
+        validate_property_without_value(&item, STATIC_REFERENCES_ATTR_PARAM_NAME)?;
         Ok(MockableAttr {
             force_static_lifetimes: true,
             ..mockable_attr
@@ -64,6 +77,36 @@ fn parse_static_references_meta_item(
     }
 }
 
+fn parse_sync_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
+    if mockable_attr.sync {
+        Err(parameter_specified_more_than_once_error(
+            SYNC_ATTR_PARAM_NAME,
+            &item,
+        ))
+    } else {
+        validate_property_without_value(&item, SYNC_ATTR_PARAM_NAME)?;
+        Ok(MockableAttr {
+            sync: true,
+            ..mockable_attr
+        })
+    }
+}
+
+fn parse_nice_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
+    if mockable_attr.nice {
+        Err(parameter_specified_more_than_once_error(
+            NICE_ATTR_PARAM_NAME,
+            &item,
+        ))
+    } else {
+        validate_property_without_value(&item, NICE_ATTR_PARAM_NAME)?;
+        Ok(MockableAttr {
+            nice: true,
+            ..mockable_attr
+        })
+    }
+}
+
 fn parse_remote_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
     match mockable_attr.remote_trait_path {
         Some(_) => Err(parameter_specified_more_than_once_error(
@@ -80,6 +123,96 @@ fn parse_remote_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<Moc
     }
 }
 
+fn parse_constants_meta_item(mockable_attr: MockableAttr, item: Meta) -> Result<MockableAttr> {
+    if !mockable_attr.constants.is_empty() {
+        return Err(parameter_specified_more_than_once_error(
+            CONSTANTS_ATTR_PARAM_NAME,
+            &item,
+        ));
+    }
+
+    let item_span = item.span();
+
+    let constants = match item {
+        Meta::List(MetaList { nested, .. }) => parse_constant_values(nested)?,
+        _ => return Err(invalid_constants_property_syntax_error(item_span)),
+    };
+
+    Ok(MockableAttr {
+        constants,
+        ..mockable_attr
+    })
+}
+
+fn parse_constant_values(nested: Punctuated<NestedMeta, Token![,]>) -> Result<Vec<ConstantValue>> {
+    let mut idents = HashSet::new();
+
+    let constants = nested.into_iter().map(|nested| match nested {
+        NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) => {
+            match path.get_ident() {
+                Some(ident) if !idents.insert(ident.clone()) => {
+                    Err(constant_specified_more_than_once_error(ident))
+                }
+                Some(ident) => Ok(ConstantValue {
+                    ident: ident.clone(),
+                    value: lit,
+                }),
+                None => Err(invalid_constants_property_syntax_error(path.span())),
+            }
+        }
+        nested => Err(invalid_constants_property_syntax_error(nested.span())),
+    });
+
+    merge_results(constants).map(Iterator::collect)
+}
+
+fn parse_supertrait_mocks_meta_item(
+    mockable_attr: MockableAttr,
+    item: Meta,
+) -> Result<MockableAttr> {
+    if !mockable_attr.supertrait_mocks.is_empty() {
+        return Err(parameter_specified_more_than_once_error(
+            SUPERTRAIT_MOCKS_ATTR_PARAM_NAME,
+            &item,
+        ));
+    }
+
+    let item_span = item.span();
+
+    let supertrait_mocks = match item {
+        Meta::List(MetaList { nested, .. }) => parse_supertrait_mocks(nested)?,
+        _ => return Err(invalid_supertrait_mocks_property_syntax_error(item_span)),
+    };
+
+    Ok(MockableAttr {
+        supertrait_mocks,
+        ..mockable_attr
+    })
+}
+
+fn parse_supertrait_mocks(
+    nested: Punctuated<NestedMeta, Token![,]>,
+) -> Result<Vec<SupertraitMock>> {
+    let supertrait_mocks = nested.into_iter().map(|nested| match nested {
+        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+            path,
+            lit: Lit::Str(str_lit),
+            ..
+        })) => str_lit
+            .parse()
+            .map(|mock_struct_ident| SupertraitMock {
+                supertrait: path,
+                mock_struct_ident,
+            })
+            .map_err(|err| invalid_supertrait_mocks_property_syntax_error(err.span())),
+        nested => Err(invalid_supertrait_mocks_property_syntax_error(
+            nested.span(),
+        )),
+    });
+
+    merge_results(supertrait_mocks).map(Iterator::collect)
+}
+
 fn get_meta_items(args: AttributeArgs) -> Result<impl Iterator<Item = Meta>> {
     let meta_items = args.into_iter().map(|nested| match nested {
         NestedMeta::Meta(meta) => Ok(meta),
@@ -133,6 +266,47 @@ fn invalid_remote_property_syntax_error(span: Span) -> Error {
         .into()
 }
 
+fn invalid_constants_property_syntax_error(span: Span) -> Error {
+    let error_message = format!(
+        "#[{attr}({param}(...))] expects a list of constants with literal values",
+        attr = ATTR_NAME,
+        param = CONSTANTS_ATTR_PARAM_NAME
+    );
+    let help_message = format!(
+        "Example usage: #[{attr}({param}(NAME = \"greeter\", MAX_LENGTH = 10))]",
+        attr = ATTR_NAME,
+        param = CONSTANTS_ATTR_PARAM_NAME
+    );
+    DiagnosticBuilder::error(span, error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
+fn invalid_supertrait_mocks_property_syntax_error(span: Span) -> Error {
+    let error_message = format!(
+        "#[{attr}({param}(...))] expects a list of supertraits with the names of their mocks",
+        attr = ATTR_NAME,
+        param = SUPERTRAIT_MOCKS_ATTR_PARAM_NAME
+    );
+    let help_message = format!(
+        "Example usage: #[{attr}({param}(Reader = \"ReaderMock\"))]",
+        attr = ATTR_NAME,
+        param = SUPERTRAIT_MOCKS_ATTR_PARAM_NAME
+    );
+    DiagnosticBuilder::error(span, error_message)
+        .help(help_message)
+        .build()
+        .into()
+}
+
+fn constant_specified_more_than_once_error(ident: &Ident) -> Error {
+    let error_message = format!("A value for `{}` is specified more than once.", ident);
+    DiagnosticBuilder::error(ident.span(), error_message)
+        .build()
+        .into()
+}
+
 fn invalid_name_property_syntax_error(span: Span) -> Error {
     let error_message = format!(
         "#[{attr}({param} = \"...\") expects a string literal",
@@ -150,27 +324,19 @@ fn invalid_name_property_syntax_error(span: Span) -> Error {
         .into()
 }
 
-fn validate_static_references_property(meta_item: &Meta) -> Result<()> {
+fn validate_property_without_value(meta_item: &Meta, name: &str) -> Result<()> {
     let meta_item_span = meta_item.span();
 
     if let Meta::Path(_) = meta_item {
         Ok(())
     } else {
-        Err(invalid_static_references_property_syntax_error(
-            meta_item_span,
-        ))
+        Err(property_without_value_syntax_error(meta_item_span, name))
     }
 }
 
-fn invalid_static_references_property_syntax_error(span: Span) -> Error {
-    let error_message = format!(
-        "#[{}({}) does not take any parameters",
-        ATTR_NAME, STATIC_REFERENCES_ATTR_PARAM_NAME
-    );
-    let help_message = format!(
-        "Correct usage: #[{}({})]",
-        ATTR_NAME, STATIC_REFERENCES_ATTR_PARAM_NAME
-    );
+fn property_without_value_syntax_error(span: Span, name: &str) -> Error {
+    let error_message = format!("#[{}({}) does not take any parameters", ATTR_NAME, name);
+    let help_message = format!("Correct usage: #[{}({})]", ATTR_NAME, name);
     DiagnosticBuilder::error(span, error_message)
         .help(help_message)
         .build()
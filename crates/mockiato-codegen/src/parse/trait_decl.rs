@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use proc_macro2::Span;
 use syn::punctuated::Punctuated;
-use syn::{Generics, Ident, ItemTrait, Token, TypeParamBound, Visibility};
+use syn::{Generics, Ident, ItemTrait, Token, Type, TypeParamBound, Visibility};
 
 use crate::parse::method_decl::MethodDecl;
 use crate::result::Result;
@@ -16,9 +16,31 @@ pub(crate) struct TraitDecl {
     pub(crate) generics: Generics,
     pub(crate) unsafety: Option<Token![unsafe]>,
     pub(crate) supertraits: Punctuated<TypeParamBound, Token![+]>,
+    pub(crate) associated_types: Vec<AssociatedTypeDecl>,
+    pub(crate) associated_consts: Vec<AssociatedConstDecl>,
     pub(crate) methods: Vec<MethodDecl>,
 }
 
+/// An associated type declared in a trait, e.g. `type Error: Debug;`
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct AssociatedTypeDecl {
+    pub(crate) ident: Ident,
+    pub(crate) bounds: Punctuated<TypeParamBound, Token![+]>,
+}
+
+/// An associated constant declared in a trait, e.g. `const NAME: &'static str;`
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct AssociatedConstDecl {
+    pub(crate) ident: Ident,
+    pub(crate) ty: Type,
+    /// Whether the trait provides a default value for this constant
+    pub(crate) has_default: bool,
+    /// The [`Span`] of the entire constant declaration
+    pub(crate) span: Span,
+}
+
 #[cfg_attr(test, mockiato::mockable)]
 pub(crate) trait TraitDeclParser: Debug {
     fn parse(&self, item: ItemTrait) -> Result<TraitDecl>;
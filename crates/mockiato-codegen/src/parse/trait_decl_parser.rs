@@ -2,12 +2,14 @@ use std::collections::HashSet;
 
 use proc_macro2::Ident;
 use syn::spanned::Spanned;
-use syn::{GenericParam, Generics, ItemTrait};
+use syn::{GenericParam, Generics, ItemTrait, TraitItem, TraitItemConst, TraitItemType};
 
 use crate::diagnostic::DiagnosticBuilder;
 use crate::parse::check_option_is_none;
-use crate::parse::method_decl::MethodDeclParser;
-use crate::parse::trait_decl::{TraitDecl, TraitDeclParser};
+use crate::parse::method_decl::{MethodDecl, MethodDeclParser};
+use crate::parse::trait_decl::{
+    AssociatedConstDecl, AssociatedTypeDecl, TraitDecl, TraitDeclParser,
+};
 use crate::result::{merge_results, Error, Result};
 
 #[derive(Debug)]
@@ -39,19 +41,49 @@ impl TraitDeclParser for TraitDeclParserImpl {
         validate_generic_type_parameters(&generics)?;
 
         let generic_types = collect_generic_type_idents(&generics);
-        let methods = items
-            .into_iter()
-            .map(move |method| self.method_decl_parser.parse(method, &generic_types));
 
-        Ok(TraitDecl {
-            visibility,
-            ident,
-            span,
-            unsafety,
-            generics,
-            supertraits,
-            methods: merge_results(methods)?.collect(),
-        })
+        let mut associated_types = Vec::new();
+        let mut associated_consts = Vec::new();
+        let mut methods = Vec::new();
+
+        for item in items {
+            match item {
+                TraitItem::Type(item_type) => {
+                    associated_types.push(parse_associated_type(item_type))
+                }
+                TraitItem::Const(item_const) => {
+                    associated_consts.push(parse_associated_const(item_const))
+                }
+                item => methods.push(self.method_decl_parser.parse(item, &generic_types)),
+            }
+        }
+
+        let associated_types = merge_results(associated_types.into_iter());
+        let methods = merge_results(methods.into_iter());
+
+        match (associated_types, methods) {
+            (Ok(associated_types), Ok(methods)) => {
+                let methods: Vec<_> = methods.collect();
+                validate_associated_functions(&methods, &generics)?;
+
+                Ok(TraitDecl {
+                    visibility,
+                    ident,
+                    span,
+                    unsafety,
+                    generics,
+                    supertraits,
+                    associated_types: associated_types.collect(),
+                    associated_consts,
+                    methods,
+                })
+            }
+            (associated_types, methods) => Err(associated_types
+                .err()
+                .into_iter()
+                .chain(methods.err())
+                .collect()),
+        }
     }
 }
 
@@ -60,11 +92,7 @@ fn validate_generic_type_parameters(generics: &Generics) -> Result<()> {
         .params
         .iter()
         .map(|generic_param| match generic_param {
-            GenericParam::Type(_) => Ok(()),
-            GenericParam::Lifetime(_) => Err(invalid_generic_param_error(
-                generic_param,
-                "Lifetimes are not supported on mockable traits",
-            )),
+            GenericParam::Type(_) | GenericParam::Lifetime(_) => Ok(()),
             GenericParam::Const(_) => Err(invalid_generic_param_error(
                 generic_param,
                 "Const generics are not supported on mockable traits",
@@ -74,6 +102,63 @@ fn validate_generic_type_parameters(generics: &Generics) -> Result<()> {
     merge_results(results).map(|_| ())
 }
 
+/// The expectations of associated functions outlive any mock instance,
+/// which is why they can't depend on the trait's lifetimes.
+fn validate_associated_functions(methods: &[MethodDecl], generics: &Generics) -> Result<()> {
+    if generics.lifetimes().next().is_none() {
+        return Ok(());
+    }
+
+    let results = methods
+        .iter()
+        .filter(|method_decl| !method_decl.has_self_arg())
+        .map(|method_decl| -> Result<()> {
+            Err(DiagnosticBuilder::error(
+                method_decl.span,
+                "Associated functions without a `self` parameter are not supported on traits with \
+                 lifetime parameters",
+            )
+            .build()
+            .into())
+        });
+
+    merge_results(results).map(|_| ())
+}
+
+fn parse_associated_type(item_type: TraitItemType) -> Result<AssociatedTypeDecl> {
+    let TraitItemType {
+        ident,
+        generics,
+        bounds,
+        ..
+    } = item_type;
+
+    if generics.params.is_empty() && generics.where_clause.is_none() {
+        Ok(AssociatedTypeDecl { ident, bounds })
+    } else {
+        Err(DiagnosticBuilder::error(
+            generics.span(),
+            "Generic associated types are not supported",
+        )
+        .build()
+        .into())
+    }
+}
+
+fn parse_associated_const(item_const: TraitItemConst) -> AssociatedConstDecl {
+    let span = item_const.span();
+    let TraitItemConst {
+        ident, ty, default, ..
+    } = item_const;
+
+    AssociatedConstDecl {
+        ident,
+        ty,
+        has_default: default.is_some(),
+        span,
+    }
+}
+
 fn collect_generic_type_idents(generics: &Generics) -> HashSet<Ident> {
     generics
         .params
@@ -2,12 +2,34 @@ use syn::{Ident, Path};
 
 pub(crate) trait PathExt {
     fn first_segment_as_ident(&self) -> Option<&Ident>;
+
+    /// Compares two paths without taking generic arguments into account
+    fn eq_ignoring_arguments(&self, other: &Path) -> bool;
+
+    /// Whether any segment of the path has generic arguments
+    fn has_arguments(&self) -> bool;
 }
 
 impl PathExt for Path {
     fn first_segment_as_ident(&self) -> Option<&Ident> {
         Some(&self.segments.iter().nth(0)?.ident)
     }
+
+    fn eq_ignoring_arguments(&self, other: &Path) -> bool {
+        self.leading_colon.is_some() == other.leading_colon.is_some()
+            && self.segments.len() == other.segments.len()
+            && self
+                .segments
+                .iter()
+                .zip(other.segments.iter())
+                .all(|(segment, other_segment)| segment.ident == other_segment.ident)
+    }
+
+    fn has_arguments(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| !segment.arguments.is_empty())
+    }
 }
 
 #[cfg(test)]
@@ -33,4 +55,34 @@ mod tests {
 
         assert_eq!(&expected_ident, path.first_segment_as_ident().unwrap());
     }
+
+    #[test]
+    fn paths_with_different_arguments_are_equal_ignoring_arguments() {
+        let path: Path = parse_quote!(io::Read<u8>);
+        let other_path: Path = parse_quote!(io::Read);
+
+        assert!(path.eq_ignoring_arguments(&other_path));
+    }
+
+    #[test]
+    fn paths_with_different_segments_are_not_equal_ignoring_arguments() {
+        let path: Path = parse_quote!(io::Read);
+        let other_path: Path = parse_quote!(Read);
+
+        assert!(!path.eq_ignoring_arguments(&other_path));
+    }
+
+    #[test]
+    fn has_arguments_returns_true_for_generic_segment() {
+        let path: Path = parse_quote!(io::Read<u8>);
+
+        assert!(path.has_arguments());
+    }
+
+    #[test]
+    fn has_arguments_returns_false_for_path_without_arguments() {
+        let path: Path = parse_quote!(io::Read);
+
+        assert!(!path.has_arguments());
+    }
 }
@@ -0,0 +1,22 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Foo {
+    const BAR: usize;
+}
+
+#[mockable(constants(BAZ = 1))]
+trait UnknownConstant {}
+
+#[mockable(constants(BAR))]
+trait MissingLiteral {
+    const BAR: usize;
+}
+
+#[mockable(constants(NAM = "greeter", MAX_LENGTH = 10))]
+trait MisspelledConstant {
+    const NAME: &'static str;
+    const MAX_LENGTH: usize;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Parser<'a> {
+    fn parse(input: &'a str) -> Self;
+}
+
+fn main() {}
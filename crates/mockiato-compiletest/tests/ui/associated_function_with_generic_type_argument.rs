@@ -0,0 +1,8 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Factory {
+    fn create<T: 'static>(value: T) -> Self;
+}
+
+fn main() {}
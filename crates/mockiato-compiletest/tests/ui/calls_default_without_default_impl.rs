@@ -0,0 +1,12 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Counter {
+    fn increment(&mut self, amount: u32) -> u32;
+}
+
+fn main() {
+    let mut counter = CounterMock::new();
+
+    counter.expect_increment(|arg| arg.any()).calls_default();
+}
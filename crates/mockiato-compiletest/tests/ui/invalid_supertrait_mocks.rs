@@ -0,0 +1,14 @@
+use mockiato::mockable;
+
+trait Source<T> {}
+
+#[mockable(supertrait_mocks(Reader = "ReaderMock"))]
+trait UnknownSupertrait {}
+
+#[mockable(supertrait_mocks(Source = "SourceMock"))]
+trait GenericSupertrait: Source<u8> {}
+
+#[mockable(supertrait_mocks(Source))]
+trait MissingMockName: Source<u8> {}
+
+fn main() {}
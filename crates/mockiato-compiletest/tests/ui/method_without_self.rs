@@ -1,8 +0,0 @@
-use mockiato::mockable;
-
-#[mockable]
-trait Foo {
-    fn bar(baz: u64);
-}
-
-fn main() {}
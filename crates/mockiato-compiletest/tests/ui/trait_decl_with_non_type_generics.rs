@@ -1,9 +0,0 @@
-use mockiato::mockable;
-
-#[mockable]
-trait TraitWithLifetime<'a> {}
-
-#[mockable]
-trait TraitWithLifetimeAndGenericParam<'a, B> {}
-
-fn main() {}
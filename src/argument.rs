@@ -9,4 +9,44 @@ impl Argument {
     pub fn internal_new() -> Self {
         Self(PhantomData)
     }
+
+    /// Uses a custom argument matcher that implements [`ArgumentMatcher`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::{mockable, ArgumentMatcher};
+    /// use std::fmt::{self, Display};
+    ///
+    /// #[derive(Debug)]
+    /// struct Even;
+    ///
+    /// impl Display for Even {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "even number")
+    ///     }
+    /// }
+    ///
+    /// impl ArgumentMatcher<u32> for Even {
+    ///     fn matches_argument(&self, input: &u32) -> bool {
+    ///         input % 2 == 0
+    ///     }
+    /// }
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Counter {
+    ///     fn add(&self, value: u32);
+    /// }
+    ///
+    /// let mut counter = CounterMock::new();
+    /// counter.expect_add(|arg| arg.matching(Even)).returns(());
+    /// counter.add(4);
+    /// ```
+    ///
+    /// [`ArgumentMatcher`]: crate::ArgumentMatcher
+    pub fn matching<M>(&self, matcher: M) -> M {
+        matcher
+    }
 }
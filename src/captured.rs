@@ -0,0 +1,113 @@
+use crate::fmt::{MaybeDebug, MaybeDebugWrapper};
+use nameof::name_of;
+use std::fmt::{self, Debug};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Holds the arguments captured by [`Argument::capture`] and [`Argument::capture_owned`],
+/// so that they can be inspected after the mocked method was called.
+///
+/// A container can be cloned cheaply, all clones refer to the same captured values.
+///
+/// [`Argument::capture`]: crate::Argument::capture
+/// [`Argument::capture_owned`]: crate::Argument::capture_owned
+pub struct Captured<T> {
+    values: Arc<Mutex<Vec<T>>>,
+}
+
+impl<T> Captured<T> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the captured values in the order in which they were captured.
+    pub fn values(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.lock().clone()
+    }
+
+    /// Returns the value that was captured last.
+    pub fn last(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.lock().last().cloned()
+    }
+
+    /// Returns the number of captured values.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no values have been captured yet.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    pub(crate) fn push(&self, value: T) {
+        self.lock().push(value);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<T>> {
+        // Pushing a value can't leave the values inconsistent,
+        // so the values of a poisoned lock are used nonetheless.
+        self.values
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<T> Clone for Captured<T> {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<T> Default for Captured<T> {
+    fn default() -> Self {
+        Self {
+            values: Arc::default(),
+        }
+    }
+}
+
+impl<T> Debug for Captured<T>
+where
+    T: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type Captured<T>))
+            .field(name_of!(values in Self), &MaybeDebugWrapper(&*self.lock()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clones_share_captured_values() {
+        let captured = Captured::new();
+        let clone = captured.clone();
+
+        clone.push(1);
+        clone.push(2);
+
+        assert_eq!(vec![1, 2], captured.values());
+        assert_eq!(Some(2), captured.last());
+        assert_eq!(2, captured.len());
+    }
+
+    #[test]
+    fn new_container_is_empty() {
+        let captured = Captured::<u32>::new();
+
+        assert!(captured.is_empty());
+        assert_eq!(None, captured.last());
+    }
+}
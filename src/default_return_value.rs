@@ -1,23 +1,33 @@
-#[cfg(rustc_is_nightly)]
 use crate::matcher::ArgumentsMatcher;
-use crate::return_value::ReturnValueGenerator;
-use std::rc::Rc;
+use crate::thread_safety::ThreadSafety;
 
 #[cfg(rustc_is_nightly)]
 use crate::return_value::Cloned;
 
-pub(crate) trait DefaultReturnValue<A>: Sized {
-    fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, Self>>> {
+pub(crate) trait DefaultReturnValue<A>: Sized
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn default_return_value<'mock, T>() -> Option<T::ReturnValueGenerator>
+    where
+        T: ThreadSafety<'mock, A, Self>,
+    {
         None
     }
 }
 
 #[cfg(not(rustc_is_nightly))]
-impl<A, T> DefaultReturnValue<A> for T {}
+impl<A, R> DefaultReturnValue<A> for R where A: for<'args> ArgumentsMatcher<'args> {}
 
 #[cfg(rustc_is_nightly)]
-impl<A, T> DefaultReturnValue<A> for T {
-    default fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, Self>>> {
+impl<A, R> DefaultReturnValue<A> for R
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    default fn default_return_value<'mock, T>() -> Option<T::ReturnValueGenerator>
+    where
+        T: ThreadSafety<'mock, A, Self>,
+    {
         None
     }
 }
@@ -27,7 +37,10 @@ impl<A> DefaultReturnValue<A> for ()
 where
     A: for<'args> ArgumentsMatcher<'args>,
 {
-    fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, ()>>> {
-        Some(Rc::new(Cloned(())))
+    fn default_return_value<'mock, T>() -> Option<T::ReturnValueGenerator>
+    where
+        T: ThreadSafety<'mock, A, ()>,
+    {
+        Some(T::store_return_value_generator(Cloned(())))
     }
 }
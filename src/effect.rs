@@ -0,0 +1,19 @@
+use crate::matcher::ArgumentsMatcher;
+
+/// A side effect that is applied to the arguments of a call.
+pub trait Effect<A>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn apply(&self, arguments: &mut <A as ArgumentsMatcher<'_>>::Arguments);
+}
+
+impl<A, F> Effect<A> for F
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    F: for<'args> Fn(&mut <A as ArgumentsMatcher<'args>>::Arguments),
+{
+    fn apply(&self, arguments: &mut <A as ArgumentsMatcher<'_>>::Arguments) {
+        self(arguments)
+    }
+}
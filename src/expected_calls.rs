@@ -32,6 +32,16 @@ impl ExpectedCalls {
         }
     }
 
+    pub(crate) fn min_value(&self) -> u64 {
+        match self.0 {
+            ExpectedCallsKind::Exact(expected) => expected,
+            ExpectedCallsKind::AtLeast(min) => min,
+            ExpectedCallsKind::Between { start, .. } => start,
+            ExpectedCallsKind::BetweenInclusive { start, .. } => start,
+            _ => 0,
+        }
+    }
+
     pub(crate) fn max_value(&self) -> Option<u64> {
         match self.0 {
             ExpectedCallsKind::Exact(expected) => Some(expected),
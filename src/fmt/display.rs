@@ -2,11 +2,11 @@ use std::fmt::{self, Display};
 
 pub(crate) struct DisplayOption<'a, D>(pub(crate) Option<&'a D>)
 where
-    D: Display;
+    D: Display + ?Sized;
 
 impl<'a, D> Display for DisplayOption<'a, D>
 where
-    D: Display,
+    D: Display + ?Sized,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.0 {
@@ -0,0 +1,610 @@
+use crate::matcher::ArgumentsMatcher;
+use crate::method::{Method, FOOTER};
+use crate::method_call::{CallOutcome, MethodCallBuilder};
+use crate::recorded_call::RecordedCall;
+use crate::thread_safety::{SingleThreaded, ThreadSafe, ThreadSafety};
+use crate::verification_report::MockVerificationReport;
+use nameof::name_of;
+use std::any::{type_name, Any, TypeId};
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::sync::{Mutex, MutexGuard};
+
+/// Holds a separate [`Method`] for every instantiation of a generic method.
+///
+/// The instantiations are identified by the [`TypeId`] of a tuple
+/// containing the method's type arguments (e.g. `(u32,)` for `store::<u32>`).
+/// The type-erased methods are recovered using [`Any`], which is why
+/// their matchers and return values need to be `'static`.
+#[allow(missing_docs)]
+pub struct GenericMethod<T = SingleThreaded>
+where
+    T: ErasedMethodStorage,
+{
+    name: &'static str,
+    methods: HashMap<TypeId, T::ErasedMethod>,
+    calls_in_order: bool,
+    /// Calls with type arguments for which no calls were expected
+    unexpected_calls: Mutex<Vec<RecordedCall>>,
+    thread_safety: PhantomData<T>,
+}
+
+impl<T> Debug for GenericMethod<T>
+where
+    T: ErasedMethodStorage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type GenericMethod<T>))
+            .field(name_of!(name in Self), &self.name)
+            .field(name_of!(methods in Self), &self.methods)
+            .field(name_of!(calls_in_order in Self), &self.calls_in_order)
+            .field(
+                name_of!(unexpected_calls in Self),
+                &*self.lock_unexpected_calls(),
+            )
+            .finish()
+    }
+}
+
+impl<T> Clone for GenericMethod<T>
+where
+    T: ErasedMethodStorage,
+{
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name,
+            methods: self
+                .methods
+                .iter()
+                .map(|(type_id, method)| (*type_id, T::as_erased(method).clone_erased()))
+                .collect(),
+            calls_in_order: self.calls_in_order,
+            unexpected_calls: Mutex::new(self.lock_unexpected_calls().clone()),
+            thread_safety: PhantomData,
+        }
+    }
+}
+
+impl<T> GenericMethod<T>
+where
+    T: ErasedMethodStorage,
+{
+    fn lock_unexpected_calls(&self) -> MutexGuard<'_, Vec<RecordedCall>> {
+        // Recording a call can't leave the recorded calls inconsistent,
+        // so the recorded calls of a poisoned lock are used nonetheless.
+        self.unexpected_calls
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[allow(missing_docs)]
+impl<T> GenericMethod<T>
+where
+    T: ErasedMethodStorage + 'static,
+{
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            methods: HashMap::new(),
+            calls_in_order: false,
+            unexpected_calls: Mutex::default(),
+            thread_safety: PhantomData,
+        }
+    }
+
+    pub fn add_expected_call<K, A, R>(
+        &mut self,
+        matcher: A,
+    ) -> MethodCallBuilder<'static, '_, A, R, T>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        T: ThreadSafety<'static, A, R>,
+        Method<'static, A, R, T>: StorableMethod<T>,
+    {
+        self.method_mut::<K, A, R>().add_expected_call(matcher)
+    }
+
+    pub fn set_stub<K, A, R>(&mut self, matcher: A) -> MethodCallBuilder<'static, '_, A, R, T>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        T: ThreadSafety<'static, A, R>,
+        Method<'static, A, R, T>: StorableMethod<T>,
+    {
+        self.method_mut::<K, A, R>().set_stub(matcher)
+    }
+
+    pub fn expect_method_calls_in_order(&mut self) {
+        self.calls_in_order = true;
+
+        for method in self.methods.values_mut() {
+            T::as_erased_mut(method).expect_method_calls_in_order();
+        }
+    }
+
+    pub fn call_unwrap<'a, K, A, R>(&self, arguments: <A as ArgumentsMatcher<'a>>::Arguments) -> R
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        T: ThreadSafety<'static, A, R>,
+    {
+        self.expected_method::<K, A, R>(&arguments)
+            .call_unwrap(arguments)
+    }
+
+    pub fn call_or_default_unwrap<'a, K, A, R>(
+        &self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> CallOutcome<<A as ArgumentsMatcher<'a>>::Arguments, R>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        T: ThreadSafety<'static, A, R>,
+    {
+        self.expected_method::<K, A, R>(&arguments)
+            .call_or_default_unwrap(arguments)
+    }
+
+    /// Used by mocks generated with `#[mockable(nice)]`.
+    /// Calls that don't match any expected call return the default value of `R` instead of panicking.
+    pub fn lenient_call_unwrap<'a, K, A, R>(
+        &self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> R
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: Default + 'static,
+        T: ThreadSafety<'static, A, R>,
+    {
+        match self.method::<K, A, R>() {
+            Some(method) => method.lenient_call_unwrap(arguments),
+            None => {
+                self.record_unexpected_call::<K, A>(&arguments);
+                R::default()
+            }
+        }
+    }
+
+    pub fn lenient_call_or_default_unwrap<'a, K, A, R>(
+        &self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> CallOutcome<<A as ArgumentsMatcher<'a>>::Arguments, R>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: Default + 'static,
+        T: ThreadSafety<'static, A, R>,
+    {
+        match self.method::<K, A, R>() {
+            Some(method) => method.lenient_call_or_default_unwrap(arguments),
+            None => {
+                self.record_unexpected_call::<K, A>(&arguments);
+                CallOutcome::Return(R::default())
+            }
+        }
+    }
+
+    pub fn verify_unwrap(&self) {
+        for method in self.methods.values() {
+            T::as_erased(method).verify_unwrap();
+        }
+    }
+
+    pub fn verify_remaining_unwrap(&self) {
+        for method in self.methods.values() {
+            T::as_erased(method).verify_remaining_unwrap();
+        }
+    }
+
+    pub fn collect_unsatisfied_expectations(&self, report: &mut MockVerificationReport) {
+        for method in self.methods.values() {
+            T::as_erased(method).collect_unsatisfied_expectations(report);
+        }
+    }
+
+    /// Returns the calls made to all instantiations, in the order in which they were made.
+    pub fn recorded_calls(&self) -> Vec<RecordedCall> {
+        let mut recorded_calls: Vec<_> = self
+            .methods
+            .values()
+            .flat_map(|method| T::as_erased(method).recorded_calls())
+            .chain(self.lock_unexpected_calls().iter().cloned())
+            .collect();
+        recorded_calls.sort_by_key(RecordedCall::sequence_number);
+        recorded_calls
+    }
+
+    /// Removes the expected calls of all instantiations.
+    pub fn reset(&mut self) {
+        for method in self.methods.values_mut() {
+            T::as_erased_mut(method).reset();
+        }
+
+        self.methods.clear();
+    }
+
+    fn expected_method<'a, K, A, R>(
+        &self,
+        arguments: &<A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> &Method<'static, A, R, T>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        T: ThreadSafety<'static, A, R>,
+    {
+        match self.method::<K, A, R>() {
+            Some(method) => method,
+            None => {
+                self.record_unexpected_call::<K, A>(arguments);
+                panic!(
+                    "\n\nThe call {}{} was not expected.\nNo calls to {} were expected for these \
+                     type arguments.\n{}\n",
+                    self.instantiation_name::<K>(),
+                    arguments,
+                    self.name,
+                    FOOTER
+                )
+            }
+        }
+    }
+
+    fn record_unexpected_call<'a, K, A>(&self, arguments: &<A as ArgumentsMatcher<'a>>::Arguments)
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args>,
+    {
+        let recorded_call =
+            RecordedCall::new(self.instantiation_name::<K>(), arguments.to_string(), None);
+
+        self.lock_unexpected_calls().push(recorded_call);
+    }
+
+    /// The name of the method including the type arguments, e.g. `GreeterMock::print::<u8>`.
+    fn instantiation_name<K>(&self) -> String
+    where
+        K: 'static,
+    {
+        format!("{}::<{}>", self.name, display_type_arguments::<K>())
+    }
+
+    fn method<K, A, R>(&self) -> Option<&Method<'static, A, R, T>>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        T: ThreadSafety<'static, A, R>,
+    {
+        self.methods
+            .get(&TypeId::of::<K>())
+            .map(|method| downcast_ref::<A, R, T>(T::as_erased(method)))
+    }
+
+    fn method_mut<K, A, R>(&mut self) -> &mut Method<'static, A, R, T>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        T: ThreadSafety<'static, A, R>,
+        Method<'static, A, R, T>: StorableMethod<T>,
+    {
+        let name = self.instantiation_name::<K>();
+        let calls_in_order = self.calls_in_order;
+
+        let method = self.methods.entry(TypeId::of::<K>()).or_insert_with(|| {
+            let mut method = Method::<A, R, T>::new(name);
+
+            if calls_in_order {
+                method.expect_method_calls_in_order();
+            }
+
+            method.into_erased()
+        });
+
+        downcast_mut::<A, R, T>(T::as_erased_mut(method))
+    }
+}
+
+/// Recovers the concrete [`Method`] from a type-erased method.
+///
+/// # Panics
+/// Panics if the method is not a `Method<'static, A, R, T>`.
+/// The generated mocks derive `A` and `R` solely from the type arguments used as key
+/// and from the generic parameters of the mock itself, so this is never the case for them.
+fn downcast_ref<A, R, T>(method: &dyn StorableMethod<T>) -> &Method<'static, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args> + 'static,
+    R: 'static,
+    T: ThreadSafety<'static, A, R> + ErasedMethodStorage + 'static,
+{
+    match method.as_any().downcast_ref() {
+        Some(method) => method,
+        None => panic_with_unexpected_method_type::<A, R, T>(method),
+    }
+}
+
+/// Mutable counterpart of [`downcast_ref`].
+fn downcast_mut<A, R, T>(method: &mut dyn StorableMethod<T>) -> &mut Method<'static, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args> + 'static,
+    R: 'static,
+    T: ThreadSafety<'static, A, R> + ErasedMethodStorage + 'static,
+{
+    if !method.as_any().is::<Method<'static, A, R, T>>() {
+        panic_with_unexpected_method_type::<A, R, T>(method);
+    }
+
+    method
+        .as_any_mut()
+        .downcast_mut()
+        .expect("The type of the method has been checked")
+}
+
+fn panic_with_unexpected_method_type<A, R, T>(method: &dyn StorableMethod<T>) -> !
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'static, A, R> + ErasedMethodStorage,
+{
+    panic!(
+        "Expected the expectations to be stored as {} instead of {}",
+        type_name::<Method<'static, A, R, T>>(),
+        method.method_type_name()
+    )
+}
+
+/// Formats the tuple of type arguments without the surrounding parentheses.
+fn display_type_arguments<K>() -> &'static str {
+    let type_arguments = type_name::<K>();
+
+    if type_arguments.starts_with('(') && type_arguments.ends_with(')') {
+        type_arguments[1..type_arguments.len() - 1].trim_end_matches(',')
+    } else {
+        type_arguments
+    }
+}
+
+/// Object-safe subset of [`Method`] that does not depend on the arguments and return type.
+pub trait ErasedMethod: Debug {
+    fn expect_method_calls_in_order(&mut self);
+
+    fn verify_unwrap(&self);
+
+    fn verify_remaining_unwrap(&self);
+
+    fn collect_unsatisfied_expectations(&self, report: &mut MockVerificationReport);
+
+    fn recorded_calls(&self) -> Vec<RecordedCall>;
+
+    fn reset(&mut self);
+
+    fn method_type_name(&self) -> &'static str;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<A, R, T> ErasedMethod for Method<'static, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args> + 'static,
+    R: 'static,
+    T: ThreadSafety<'static, A, R> + 'static,
+{
+    fn expect_method_calls_in_order(&mut self) {
+        Method::expect_method_calls_in_order(self)
+    }
+
+    fn verify_unwrap(&self) {
+        Method::verify_unwrap(self)
+    }
+
+    fn verify_remaining_unwrap(&self) {
+        Method::verify_remaining_unwrap(self)
+    }
+
+    fn collect_unsatisfied_expectations(&self, report: &mut MockVerificationReport) {
+        Method::collect_unsatisfied_expectations(self, report)
+    }
+
+    fn reset(&mut self) {
+        Method::reset(self)
+    }
+
+    fn recorded_calls(&self) -> Vec<RecordedCall> {
+        Method::recorded_calls(self)
+    }
+
+    fn method_type_name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Implemented by the thread safety markers to choose how the type-erased methods are stored.
+/// The methods of thread safe mocks are stored as trait objects that are [`Send`] and [`Sync`].
+pub trait ErasedMethodStorage: Sized {
+    type ErasedMethod: Debug;
+
+    fn as_erased(method: &Self::ErasedMethod) -> &dyn StorableMethod<Self>;
+
+    fn as_erased_mut(method: &mut Self::ErasedMethod) -> &mut dyn StorableMethod<Self>;
+}
+
+/// A type-erased method that can be stored in a [`GenericMethod`] with the thread safety `T`.
+pub trait StorableMethod<T>: ErasedMethod
+where
+    T: ErasedMethodStorage,
+{
+    fn into_erased(self) -> T::ErasedMethod
+    where
+        Self: Sized;
+
+    fn clone_erased(&self) -> T::ErasedMethod;
+}
+
+impl ErasedMethodStorage for SingleThreaded {
+    type ErasedMethod = Box<dyn StorableMethod<Self>>;
+
+    fn as_erased(method: &Self::ErasedMethod) -> &dyn StorableMethod<Self> {
+        &**method
+    }
+
+    fn as_erased_mut(method: &mut Self::ErasedMethod) -> &mut dyn StorableMethod<Self> {
+        &mut **method
+    }
+}
+
+impl<A, R> StorableMethod<SingleThreaded> for Method<'static, A, R, SingleThreaded>
+where
+    A: for<'args> ArgumentsMatcher<'args> + 'static,
+    R: 'static,
+{
+    fn into_erased(self) -> Box<dyn StorableMethod<SingleThreaded>> {
+        Box::new(self)
+    }
+
+    fn clone_erased(&self) -> Box<dyn StorableMethod<SingleThreaded>> {
+        Box::new(self.clone())
+    }
+}
+
+impl ErasedMethodStorage for ThreadSafe {
+    type ErasedMethod = Box<dyn StorableMethod<Self> + Send + Sync>;
+
+    fn as_erased(method: &Self::ErasedMethod) -> &dyn StorableMethod<Self> {
+        &**method
+    }
+
+    fn as_erased_mut(method: &mut Self::ErasedMethod) -> &mut dyn StorableMethod<Self> {
+        &mut **method
+    }
+}
+
+impl<A, R> StorableMethod<ThreadSafe> for Method<'static, A, R, ThreadSafe>
+where
+    A: for<'args> ArgumentsMatcher<'args> + Send + Sync + 'static,
+    R: 'static,
+{
+    fn into_erased(self) -> Box<dyn StorableMethod<ThreadSafe> + Send + Sync> {
+        Box::new(self)
+    }
+
+    fn clone_erased(&self) -> Box<dyn StorableMethod<ThreadSafe> + Send + Sync> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+
+    #[test]
+    fn calls_are_dispatched_to_the_matching_instantiation() {
+        let mut method: GenericMethod = GenericMethod::new("test");
+
+        method
+            .add_expected_call::<(u32,), _, _>(ArgumentsMatcherMock::new(Some(true)))
+            .returns(String::from("u32"));
+        method
+            .add_expected_call::<(String,), _, _>(ArgumentsMatcherMock::new(Some(true)))
+            .returns(String::from("String"));
+
+        assert_eq!(
+            "String",
+            method.call_unwrap::<(String,), ArgumentsMatcherMock, String>(ArgumentsMock)
+        );
+        assert_eq!(
+            "u32",
+            method.call_unwrap::<(u32,), ArgumentsMatcherMock, String>(ArgumentsMock)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "No calls to test were expected for these type arguments")]
+    fn call_panics_for_unexpected_instantiation() {
+        let mut method: GenericMethod = GenericMethod::new("test");
+
+        method
+            .add_expected_call::<(u32,), _, ()>(ArgumentsMatcherMock::new(None))
+            .times(0..);
+
+        method.call_unwrap::<(u64,), ArgumentsMatcherMock, ()>(ArgumentsMock);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected the expectations to be stored as")]
+    fn call_panics_if_instantiation_was_stored_with_different_return_type() {
+        let mut method: GenericMethod = GenericMethod::new("test");
+
+        method
+            .add_expected_call::<(u32,), _, String>(ArgumentsMatcherMock::new(Some(true)))
+            .returns(String::from("u32"));
+
+        method.call_unwrap::<(u32,), ArgumentsMatcherMock, u8>(ArgumentsMock);
+    }
+
+    #[test]
+    fn lenient_call_returns_default_value_for_unexpected_instantiation() {
+        let method: GenericMethod = GenericMethod::new("test");
+
+        assert_eq!(
+            "",
+            method.lenient_call_unwrap::<(u64,), ArgumentsMatcherMock, String>(ArgumentsMock)
+        );
+
+        let recorded_calls = method.recorded_calls();
+        assert_eq!(1, recorded_calls.len());
+        assert_eq!("test::<u64>", recorded_calls[0].method_name());
+        assert_eq!(None, recorded_calls[0].matched_expected_call());
+    }
+
+    #[test]
+    #[should_panic(expected = "The expected calls for test::<u32> were not satisified.")]
+    fn verify_checks_all_instantiations() {
+        let mut method: GenericMethod = GenericMethod::new("test");
+
+        method
+            .add_expected_call::<(u32,), _, ()>(ArgumentsMatcherMock::new(None))
+            .returns(());
+
+        method.verify_unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "No calls to test were expected for these type arguments")]
+    fn reset_removes_all_instantiations() {
+        let mut method: GenericMethod = GenericMethod::new("test");
+
+        method
+            .add_expected_call::<(u32,), _, ()>(ArgumentsMatcherMock::new(None))
+            .returns(());
+        method.reset();
+        method.verify_unwrap();
+
+        method.call_unwrap::<(u32,), ArgumentsMatcherMock, ()>(ArgumentsMock);
+    }
+
+    #[test]
+    fn type_arguments_are_displayed_without_parentheses() {
+        assert_eq!("u32", display_type_arguments::<(u32,)>());
+        assert_eq!("u32, u8", display_type_arguments::<(u32, u8)>());
+        assert_eq!("(u32, u8)", display_type_arguments::<((u32, u8),)>());
+    }
+}
@@ -5,5 +5,10 @@
 
 pub use crate::arguments::Arguments;
 pub use crate::fmt::MaybeDebugWrapper;
-pub use crate::matcher::{ArgumentMatcher, ArgumentsMatcher};
+pub use crate::generic_method::GenericMethod;
+pub use crate::matcher::{ArgumentMatcher, ArgumentsMatcher, HasDefaultImpl};
 pub use crate::method::Method;
+pub use crate::method_call::CallOutcome;
+pub use crate::mock::Mock;
+pub use crate::static_expectations::StaticExpectations;
+pub use crate::thread_safety::{SingleThreaded, ThreadSafe};
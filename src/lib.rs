@@ -25,7 +25,7 @@
 //!
 //! # Configuring Expected Calls
 //!
-//! Each method on the trait receives two companion methods on the mock struct:
+//! Each method on the trait receives the following companion methods on the mock struct:
 //!
 //! ## `expect_<method_name>`
 //!
@@ -35,6 +35,7 @@
 //! It has the same amount of arguments as the mocked method.
 //! Each argument accepts a closure that is invoked with a reference to [`Argument`], which lets
 //! you create different argument matchers.
+//! Custom argument matchers implement [`ArgumentMatcher`] and are passed to [`Argument::matching`].
 //!
 //! This method returns a [`MethodCallBuilder`] which allows for further customization of an expected call's behavior.
 //!
@@ -76,6 +77,37 @@
 //! message_sender.expect_send_message_calls_in_order();
 //! ```
 //!
+//! Calls to different methods or mocks can be ordered using a [`Sequence`].
+//!
+//! ## `stub_<method_name>`
+//!
+//! This associated function of the mock configures a fallback for calls that don't match any expected call
+//! or whose matching expected calls have already been called the maximum number of times.
+//! The stub matches any arguments, may be called any number of times and is not verified.
+//! It never conflicts with expected calls, so specific expectations can be combined with a stub.
+//!
+//! ```
+//! # use mockiato::mockable;
+//! #
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! # trait Greeter {
+//! #     fn greet(&self, name: &str) -> String;
+//! # }
+//! #
+//! let mut greeter = GreeterMock::new();
+//!
+//! greeter
+//!     .expect_greet(|arg| arg.partial_eq("Jane"))
+//!     .returns(String::from("Hello Jane"));
+//! GreeterMock::stub_greet(&mut greeter).returns(String::from("Hello stranger"));
+//!
+//! assert_eq!("Hello stranger", greeter.greet("Doe"));
+//! assert_eq!("Hello Jane", greeter.greet("Jane"));
+//! ```
+//!
 //! # Call Verification
 //! Mockiato automatically verifies that all expected calls were made when the mock goes out of scope.
 //! The mock panics when a method is called that was not configured, or if the parameters did not match.
@@ -106,6 +138,43 @@
 //!     // <--  and panics otherwise
 //! }
 //! ```
+//!
+//! The expected calls can be verified without panicking using [`verify`], which consumes the mock.
+//! It returns a [`MockVerificationReport`] that lists all expected calls that were not satisfied.
+//!
+//! The expected calls can also be verified before the mock goes out of scope using [`checkpoint`].
+//! This removes the expected calls, so that new expectations can be configured afterwards.
+//! ```
+//! # use mockiato::mockable;
+//! #
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! # trait Greeter {
+//! #     fn greet(&self, name: &str) -> String;
+//! # }
+//! #
+//! let mut greeter = GreeterMock::new();
+//!
+//! greeter
+//!     .expect_greet(|arg| arg.partial_eq("Doe"))
+//!     .returns(String::from("Hello Doe"));
+//! assert_eq!("Hello Doe", greeter.greet("Doe"));
+//!
+//! mockiato::checkpoint(&mut greeter);
+//!
+//! greeter
+//!     .expect_greet(|arg| arg.partial_eq("Doe"))
+//!     .returns(String::from("Goodbye Doe"));
+//! assert_eq!("Goodbye Doe", greeter.greet("Doe"));
+//! ```
+//!
+//! # Call History
+//! Mocks record all calls made to them, including calls that were not expected.
+//! The calls can be inspected using [`calls`] or per method using the associated function
+//! `calls_to_<method_name>` of the mock (e.g. `GreeterMock::calls_to_greet(&greeter)`),
+//! which return a list of [`RecordedCall`]s in the order in which the calls were made.
 
 #![cfg_attr(rustc_is_nightly, feature(doc_cfg, external_doc, specialization))]
 #![warn(
@@ -195,23 +264,84 @@
 ///     fn flush(&mut self) -> io::Result<()>;
 /// }
 /// ```
+///
+/// ## `supertrait_mocks`
+/// Implements the supertraits of the trait by forwarding all calls to mocks of the supertraits.
+/// The mocks of the supertraits have to be generated before the trait's mock.
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// #[cfg_attr(test, mockable)]
+/// trait Reader {
+///     fn read(&self) -> u8;
+/// }
+///
+/// #[cfg_attr(test, mockable(supertrait_mocks(Reader = "ReaderMock")))]
+/// trait Connection: Reader {
+///     fn send(&self, data: u8);
+/// }
+/// ```
+///
+/// ## `sync`
+/// Generates a mock that is `Send` and `Sync`, so that it can be shared with other threads.
+/// Argument matchers and return values of the mock's expectations need to be `Send` and `Sync`.
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// #[cfg_attr(test, mockable(sync))]
+/// trait Service {
+///     fn handle(&self, request: &str) -> String;
+/// }
+/// ```
+///
+/// ## `nice`
+/// Generates a mock that returns the [`Default`] value of the return type for calls
+/// that don't match any expected call, instead of panicking.
+/// Such calls are recorded as unexpected calls. Expected calls are still verified when the mock is dropped.
+/// The return types of all methods need to implement [`Default`].
+///
+/// ```
+/// use mockiato::mockable;
+///
+/// #[cfg_attr(test, mockable(nice))]
+/// trait Service {
+///     fn handle(&self, request: &str) -> String;
+/// }
+/// ```
 pub use mockiato_codegen::mockable;
 
 #[cfg_attr(rustc_is_nightly, doc(include = "../readme.md"))]
 mod test_readme {}
 
 pub use crate::argument::Argument;
+pub use crate::captured::Captured;
 pub use crate::expected_calls::ExpectedCalls;
+pub use crate::matcher::ArgumentMatcher;
 pub use crate::method_call::MethodCallBuilder;
+pub use crate::mock::{calls, checkpoint, verify};
+pub use crate::recorded_call::RecordedCall;
+pub use crate::sequence::Sequence;
+pub use crate::verification_report::{MockVerificationReport, UnsatisfiedExpectation};
 
 mod argument;
 mod arguments;
+mod captured;
 mod default_return_value;
+mod effect;
 mod expected_calls;
 mod fmt;
+mod generic_method;
 #[doc(hidden)]
 pub mod internal;
 mod matcher;
 mod method;
 mod method_call;
+mod mock;
+mod recorded_call;
 mod return_value;
+mod sequence;
+mod static_expectations;
+mod thread_safety;
+mod verification_report;
@@ -2,13 +2,108 @@ use crate::arguments::Arguments;
 use std::fmt::{Debug, Display};
 
 pub(crate) mod any;
+pub(crate) mod capture;
+pub(crate) mod logical;
 pub(crate) mod nearly_eq;
+pub(crate) mod ordering;
 pub(crate) mod partial_eq;
+#[cfg(feature = "regex")]
+pub(crate) mod regex;
+pub(crate) mod satisfies;
+pub(crate) mod string;
 pub(crate) mod unordered_vec_eq;
 
-#[allow(missing_docs)]
+/// Matches an argument of type `T` of a call.
+///
+/// Argument matchers are created by the closures passed to the `expect_<method_name>` methods
+/// of a generated mock. In addition to the matchers provided by [`Argument`], custom matchers
+/// can be used by implementing this trait and passing them to [`Argument::matching`].
+///
+/// # Display
+/// The [`Display`] implementation is used to describe the expected call in failure messages,
+/// e.g. `send_order(status == Paid)`. It should describe the values that the matcher accepts,
+/// without mentioning the argument's name or type.
+///
+/// # Arguments that are references
+/// Arguments of a reference type (e.g. `&Order`) may have any lifetime,
+/// so the matcher needs to be implemented for all lifetimes: `impl ArgumentMatcher<&Order>`.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, ArgumentMatcher};
+/// use std::fmt::{self, Display};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Status {
+///     Pending,
+///     Paid,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Order {
+///     status: Status,
+/// }
+///
+/// #[derive(Debug)]
+/// struct StatusMatcher(Status);
+///
+/// impl Display for StatusMatcher {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "status == {:?}", self.0)
+///     }
+/// }
+///
+/// impl ArgumentMatcher<&Order> for StatusMatcher {
+///     fn matches_argument(&self, input: &&Order) -> bool {
+///         input.status == self.0
+///     }
+/// }
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait OrderProcessor {
+///     fn process(&self, order: &Order);
+/// }
+///
+/// # fn main() {
+/// let mut processor = OrderProcessorMock::new();
+/// processor
+///     .expect_process(|arg| arg.matching(StatusMatcher(Status::Paid)))
+///     .returns(());
+///
+/// processor.process(&Order {
+///     status: Status::Paid,
+/// });
+/// # }
+/// ```
+///
+/// [`Argument`]: crate::Argument
+/// [`Argument::matching`]: crate::Argument::matching
+#[cfg_attr(rustc_is_nightly, allow(clippy::needless_doctest_main))]
 pub trait ArgumentMatcher<T>: Display + Debug {
+    /// Returns `true` if the argument is accepted by this matcher.
+    /// This may be called multiple times per call, e.g. when looking for a matching expected call.
     fn matches_argument(&self, input: &T) -> bool;
+
+    /// Called with the argument when a call is made that was matched to the
+    /// expected call that this matcher belongs to.
+    /// In contrast to [`ArgumentMatcher::matches_argument`], this is only called once per call.
+    fn on_call(&self, _input: &T) {}
+}
+
+impl<T, M> ArgumentMatcher<T> for Box<M>
+where
+    M: ArgumentMatcher<T> + ?Sized,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        (**self).matches_argument(input)
+    }
+
+    fn on_call(&self, input: &T) {
+        (**self).on_call(input)
+    }
 }
 
 #[allow(missing_docs)]
@@ -16,8 +111,16 @@ pub trait ArgumentsMatcher<'args>: Display + Debug {
     type Arguments: Arguments;
 
     fn matches_arguments(&self, input: &Self::Arguments) -> bool;
+
+    /// Forwards the arguments of a call to [`ArgumentMatcher::on_call`] of the argument matchers.
+    fn on_call(&self, _input: &Self::Arguments) {}
 }
 
+/// Implemented for the arguments matchers of methods that have a default implementation.
+/// Only these methods can be configured to call it.
+#[allow(missing_docs)]
+pub trait HasDefaultImpl {}
+
 #[cfg(test)]
 pub(crate) use self::mock::*;
 
@@ -0,0 +1,163 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use crate::captured::Captured;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches any value and captures a clone of
+    /// the argument into the given container whenever the expected call is called.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::{mockable, Captured};
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Counter {
+    ///     fn add(&self, value: u32);
+    /// }
+    ///
+    /// let captured = Captured::new();
+    /// let mut counter = CounterMock::new();
+    /// counter
+    ///     .expect_add(|arg| arg.capture(&captured))
+    ///     .times(2)
+    ///     .returns(());
+    ///
+    /// counter.add(1);
+    /// counter.add(2);
+    ///
+    /// assert_eq!(vec![1, 2], captured.values());
+    /// ```
+    pub fn capture<T>(&self, captured: &Captured<T>) -> CaptureArgumentMatcher<T> {
+        CaptureArgumentMatcher {
+            captured: captured.clone(),
+        }
+    }
+
+    /// Creates an argument matcher that matches any reference and captures an owned copy of
+    /// the referenced value into the given container whenever the expected call is called.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::{mockable, Captured};
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait MessageSender {
+    ///     fn send_message(&self, message: &str);
+    /// }
+    ///
+    /// let captured = Captured::new();
+    /// let mut sender = MessageSenderMock::new();
+    /// sender
+    ///     .expect_send_message(|arg| arg.capture_owned(&captured))
+    ///     .returns(());
+    ///
+    /// sender.send_message("Hello World");
+    ///
+    /// assert_eq!(Some(String::from("Hello World")), captured.last());
+    /// ```
+    pub fn capture_owned<T>(&self, captured: &Captured<T>) -> OwnedCaptureArgumentMatcher<T> {
+        OwnedCaptureArgumentMatcher {
+            captured: captured.clone(),
+        }
+    }
+}
+
+pub struct CaptureArgumentMatcher<T> {
+    captured: Captured<T>,
+}
+
+impl<T> Display for CaptureArgumentMatcher<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "*")
+    }
+}
+
+impl<T> Debug for CaptureArgumentMatcher<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type CaptureArgumentMatcher<T>))
+            .field(name_of!(captured in Self), &self.captured)
+            .finish()
+    }
+}
+
+impl<T> ArgumentMatcher<T> for CaptureArgumentMatcher<T>
+where
+    T: Clone,
+{
+    fn matches_argument(&self, _input: &T) -> bool {
+        true
+    }
+
+    fn on_call(&self, input: &T) {
+        self.captured.push(input.clone());
+    }
+}
+
+pub struct OwnedCaptureArgumentMatcher<T> {
+    captured: Captured<T>,
+}
+
+impl<T> Display for OwnedCaptureArgumentMatcher<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "*")
+    }
+}
+
+impl<T> Debug for OwnedCaptureArgumentMatcher<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type OwnedCaptureArgumentMatcher<T>))
+            .field(name_of!(captured in Self), &self.captured)
+            .finish()
+    }
+}
+
+impl<'args, T, U> ArgumentMatcher<&'args U> for OwnedCaptureArgumentMatcher<T>
+where
+    U: ToOwned<Owned = T> + ?Sized,
+{
+    fn matches_argument(&self, _input: &&U) -> bool {
+        true
+    }
+
+    fn on_call(&self, input: &&U) {
+        self.captured.push((*input).to_owned());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn capture_stores_argument_on_call() {
+        let captured = Captured::new();
+        let matcher = Argument::internal_new().capture(&captured);
+
+        assert!(matcher.matches_argument(&1));
+        assert!(captured.is_empty());
+
+        matcher.on_call(&1);
+
+        assert_eq!(vec![1], captured.values());
+    }
+
+    #[test]
+    fn capture_owned_stores_owned_argument_on_call() {
+        let captured = Captured::new();
+        let matcher = Argument::internal_new().capture_owned(&captured);
+
+        assert!(matcher.matches_argument(&"foo"));
+
+        matcher.on_call(&"foo");
+
+        assert_eq!(Some(String::from("foo")), captured.last());
+    }
+}
@@ -0,0 +1,276 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches values that are not matched by the given matcher.
+    /// Capturing matchers within the given matcher don't capture any arguments,
+    /// since the given matcher doesn't match the arguments of matched calls.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Divider {
+    ///     fn divide(&self, divisor: u32) -> u32;
+    /// }
+    ///
+    /// let mut divider = DividerMock::new();
+    /// divider
+    ///     .expect_divide(|arg| arg.not(arg.partial_eq(0)))
+    ///     .returns(1);
+    /// divider.divide(3);
+    /// ```
+    pub fn not<M>(&self, matcher: M) -> NotArgumentMatcher<M> {
+        NotArgumentMatcher { matcher }
+    }
+
+    /// Creates an argument matcher that matches values that are matched by all of the given matchers.
+    ///
+    /// Matchers of different types can be combined by boxing them,
+    /// e.g. as `Box<dyn ArgumentMatcher<u32>>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Divider {
+    ///     fn divide(&self, divisor: u32) -> u32;
+    /// }
+    ///
+    /// let mut divider = DividerMock::new();
+    /// divider
+    ///     .expect_divide(|arg| {
+    ///         arg.all_of(vec![arg.not(arg.partial_eq(0)), arg.not(arg.partial_eq(1))])
+    ///     })
+    ///     .returns(1);
+    /// divider.divide(3);
+    /// ```
+    pub fn all_of<M>(&self, matchers: Vec<M>) -> AllOfArgumentMatcher<M> {
+        AllOfArgumentMatcher { matchers }
+    }
+
+    /// Creates an argument matcher that matches values that are matched by any of the given matchers.
+    ///
+    /// Matchers of different types can be combined by boxing them,
+    /// e.g. as `Box<dyn ArgumentMatcher<u32>>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Divider {
+    ///     fn divide(&self, divisor: u32) -> u32;
+    /// }
+    ///
+    /// let mut divider = DividerMock::new();
+    /// divider
+    ///     .expect_divide(|arg| arg.any_of(vec![arg.partial_eq(1), arg.partial_eq(2)]))
+    ///     .times(2)
+    ///     .returns(1);
+    /// divider.divide(1);
+    /// divider.divide(2);
+    /// ```
+    pub fn any_of<M>(&self, matchers: Vec<M>) -> AnyOfArgumentMatcher<M> {
+        AnyOfArgumentMatcher { matchers }
+    }
+}
+
+pub struct NotArgumentMatcher<M> {
+    matcher: M,
+}
+
+impl<M> Display for NotArgumentMatcher<M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not({})", self.matcher)
+    }
+}
+
+impl<M> Debug for NotArgumentMatcher<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type NotArgumentMatcher<M>))
+            .field(name_of!(matcher in Self), &self.matcher)
+            .finish()
+    }
+}
+
+impl<T, M> ArgumentMatcher<T> for NotArgumentMatcher<M>
+where
+    M: ArgumentMatcher<T>,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        !self.matcher.matches_argument(input)
+    }
+}
+
+pub struct AllOfArgumentMatcher<M> {
+    matchers: Vec<M>,
+}
+
+impl<M> Display for AllOfArgumentMatcher<M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({})", DisplayJoined(&self.matchers, " & "))
+    }
+}
+
+impl<M> Debug for AllOfArgumentMatcher<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type AllOfArgumentMatcher<M>))
+            .field(name_of!(matchers in Self), &self.matchers)
+            .finish()
+    }
+}
+
+impl<T, M> ArgumentMatcher<T> for AllOfArgumentMatcher<M>
+where
+    M: ArgumentMatcher<T>,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        self.matchers
+            .iter()
+            .all(|matcher| matcher.matches_argument(input))
+    }
+
+    fn on_call(&self, input: &T) {
+        for matcher in &self.matchers {
+            matcher.on_call(input);
+        }
+    }
+}
+
+pub struct AnyOfArgumentMatcher<M> {
+    matchers: Vec<M>,
+}
+
+impl<M> Display for AnyOfArgumentMatcher<M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({})", DisplayJoined(&self.matchers, " | "))
+    }
+}
+
+impl<M> Debug for AnyOfArgumentMatcher<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type AnyOfArgumentMatcher<M>))
+            .field(name_of!(matchers in Self), &self.matchers)
+            .finish()
+    }
+}
+
+impl<T, M> ArgumentMatcher<T> for AnyOfArgumentMatcher<M>
+where
+    M: ArgumentMatcher<T>,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        self.matchers
+            .iter()
+            .any(|matcher| matcher.matches_argument(input))
+    }
+
+    /// Only the matchers that match the argument are notified of the call.
+    fn on_call(&self, input: &T) {
+        for matcher in &self.matchers {
+            if matcher.matches_argument(input) {
+                matcher.on_call(input);
+            }
+        }
+    }
+}
+
+struct DisplayJoined<'a, M>(&'a [M], &'static str);
+
+impl<'a, M> Display for DisplayJoined<'a, M>
+where
+    M: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let DisplayJoined(matchers, separator) = self;
+
+        for (index, matcher) in matchers.iter().enumerate() {
+            if index > 0 {
+                f.write_str(separator)?;
+            }
+            write!(f, "{}", matcher)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn is(value: u32) -> impl ArgumentMatcher<u32> {
+        Argument::internal_new().satisfies(value.to_string(), move |input: &u32| *input == value)
+    }
+
+    #[test]
+    fn not_inverts_matcher() {
+        let matcher = Argument::internal_new().not(is(0));
+
+        assert!(matcher.matches_argument(&1));
+        assert!(!matcher.matches_argument(&0));
+        assert_eq!("not(0)", matcher.to_string());
+    }
+
+    #[test]
+    fn all_of_matches_if_all_matchers_match() {
+        let argument = Argument::internal_new();
+        let matcher = argument.all_of(vec![argument.not(is(1)), argument.not(is(2))]);
+
+        assert!(matcher.matches_argument(&3));
+        assert!(!matcher.matches_argument(&2));
+        assert_eq!("(not(1) & not(2))", matcher.to_string());
+    }
+
+    #[test]
+    fn any_of_matches_if_any_matcher_matches() {
+        let matcher = Argument::internal_new().any_of(vec![
+            Box::new(is(1)) as Box<dyn ArgumentMatcher<u32>>,
+            Box::new(is(2)),
+        ]);
+
+        assert!(matcher.matches_argument(&2));
+        assert!(!matcher.matches_argument(&3));
+        assert_eq!("(1 | 2)", matcher.to_string());
+    }
+
+    #[test]
+    fn any_of_without_matchers_matches_nothing() {
+        let matcher = Argument::internal_new().any_of(Vec::<Box<dyn ArgumentMatcher<u32>>>::new());
+
+        assert!(!matcher.matches_argument(&1));
+        assert_eq!("()", matcher.to_string());
+    }
+}
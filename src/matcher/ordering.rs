@@ -0,0 +1,349 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use crate::fmt::{MaybeDebug, MaybeDebugWrapper};
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+impl Argument {
+    /// Creates an argument matcher that matches values greater than the given value using [`PartialOrd`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: f64);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.gt(5.0))
+    ///     .returns(());
+    /// thermostat.set_temperature(21.5);
+    /// ```
+    pub fn gt<T>(&self, value: T) -> OrderingArgumentMatcher<T> {
+        OrderingArgumentMatcher::new(value, Comparison::Greater)
+    }
+
+    /// Creates an argument matcher that matches values greater than or equal to the given value
+    /// using [`PartialOrd`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: f64);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.ge(5.0))
+    ///     .returns(());
+    /// thermostat.set_temperature(5.0);
+    /// ```
+    pub fn ge<T>(&self, value: T) -> OrderingArgumentMatcher<T> {
+        OrderingArgumentMatcher::new(value, Comparison::GreaterOrEqual)
+    }
+
+    /// Creates an argument matcher that matches values less than the given value using [`PartialOrd`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: f64);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.lt(30.0))
+    ///     .returns(());
+    /// thermostat.set_temperature(21.5);
+    /// ```
+    pub fn lt<T>(&self, value: T) -> OrderingArgumentMatcher<T> {
+        OrderingArgumentMatcher::new(value, Comparison::Less)
+    }
+
+    /// Creates an argument matcher that matches values less than or equal to the given value
+    /// using [`PartialOrd`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: f64);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.le(30.0))
+    ///     .returns(());
+    /// thermostat.set_temperature(30.0);
+    /// ```
+    pub fn le<T>(&self, value: T) -> OrderingArgumentMatcher<T> {
+        OrderingArgumentMatcher::new(value, Comparison::LessOrEqual)
+    }
+
+    /// Creates an argument matcher that matches values contained in the given range using [`PartialOrd`].
+    ///
+    /// # Accepted values
+    /// | Description          | Type                 | Example   |
+    /// | -------------------- | -------------------- | --------- |
+    /// | Any value            | [`RangeFull`]        | `..`      |
+    /// | At least             | [`RangeFrom`]        | `10..`    |
+    /// | Less than            | [`RangeTo`]          | `..20`    |
+    /// | At most              | [`RangeToInclusive`] | `..=20`   |
+    /// | Between (exclusive)  | [`Range`]            | `10..20`  |
+    /// | Between (inclusive)  | [`RangeInclusive`]   | `10..=20` |
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Thermostat {
+    ///     fn set_temperature(&self, celsius: u8);
+    /// }
+    ///
+    /// let mut thermostat = ThermostatMock::new();
+    /// thermostat
+    ///     .expect_set_temperature(|arg| arg.in_range(10..20))
+    ///     .returns(());
+    /// thermostat.set_temperature(15);
+    /// ```
+    ///
+    /// [`RangeFull`]: std::ops::RangeFull
+    /// [`RangeFrom`]: std::ops::RangeFrom
+    /// [`RangeTo`]: std::ops::RangeTo
+    /// [`RangeToInclusive`]: std::ops::RangeToInclusive
+    /// [`Range`]: std::ops::Range
+    /// [`RangeInclusive`]: std::ops::RangeInclusive
+    pub fn in_range<T, R>(&self, range: R) -> InRangeArgumentMatcher<T, R>
+    where
+        R: RangeBounds<T>,
+    {
+        InRangeArgumentMatcher {
+            range,
+            bound_type: PhantomData,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Comparison {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = match self {
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+        };
+
+        f.write_str(operator)
+    }
+}
+
+pub struct OrderingArgumentMatcher<T> {
+    value: T,
+    comparison: Comparison,
+}
+
+impl<T> OrderingArgumentMatcher<T> {
+    fn new(value: T, comparison: Comparison) -> Self {
+        Self { value, comparison }
+    }
+}
+
+impl<T> Display for OrderingArgumentMatcher<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.comparison)?;
+        MaybeDebug::fmt(&self.value, f)
+    }
+}
+
+impl<T> Debug for OrderingArgumentMatcher<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type OrderingArgumentMatcher<T>))
+            .field(name_of!(value in Self), &MaybeDebugWrapper(&self.value))
+            .field(name_of!(comparison in Self), &self.comparison)
+            .finish()
+    }
+}
+
+impl<T, U> ArgumentMatcher<U> for OrderingArgumentMatcher<T>
+where
+    U: PartialOrd<T>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        match self.comparison {
+            Comparison::Greater => *input > self.value,
+            Comparison::GreaterOrEqual => *input >= self.value,
+            Comparison::Less => *input < self.value,
+            Comparison::LessOrEqual => *input <= self.value,
+        }
+    }
+}
+
+pub struct InRangeArgumentMatcher<T, R> {
+    range: R,
+    bound_type: PhantomData<fn() -> T>,
+}
+
+impl<T, R> Display for InRangeArgumentMatcher<T, R>
+where
+    R: RangeBounds<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "in ")?;
+
+        match self.range.start_bound() {
+            Bound::Included(start) | Bound::Excluded(start) => MaybeDebug::fmt(start, f)?,
+            Bound::Unbounded => {}
+        }
+
+        match self.range.end_bound() {
+            Bound::Included(end) => {
+                write!(f, "..=")?;
+                MaybeDebug::fmt(end, f)
+            }
+            Bound::Excluded(end) => {
+                write!(f, "..")?;
+                MaybeDebug::fmt(end, f)
+            }
+            Bound::Unbounded => write!(f, ".."),
+        }
+    }
+}
+
+impl<T, R> Debug for InRangeArgumentMatcher<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type InRangeArgumentMatcher<T, R>))
+            .field(name_of!(range in Self), &MaybeDebugWrapper(&self.range))
+            .finish()
+    }
+}
+
+impl<T, R, U> ArgumentMatcher<U> for InRangeArgumentMatcher<T, R>
+where
+    R: RangeBounds<T>,
+    T: PartialOrd<U>,
+    U: PartialOrd<T>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        self.range.contains(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gt_matches_greater_values() {
+        let matcher = Argument::internal_new().gt(5);
+
+        assert!(matcher.matches_argument(&6));
+        assert!(!matcher.matches_argument(&5));
+    }
+
+    #[test]
+    fn ge_matches_greater_and_equal_values() {
+        let matcher = Argument::internal_new().ge(5);
+
+        assert!(matcher.matches_argument(&5));
+        assert!(!matcher.matches_argument(&4));
+    }
+
+    #[test]
+    fn lt_matches_less_values() {
+        let matcher = Argument::internal_new().lt(5);
+
+        assert!(matcher.matches_argument(&4));
+        assert!(!matcher.matches_argument(&5));
+    }
+
+    #[test]
+    fn le_matches_less_and_equal_values() {
+        let matcher = Argument::internal_new().le(5);
+
+        assert!(matcher.matches_argument(&5));
+        assert!(!matcher.matches_argument(&6));
+    }
+
+    #[test]
+    fn in_range_matches_values_in_range() {
+        let matcher = Argument::internal_new().in_range(10..20);
+
+        assert!(matcher.matches_argument(&10));
+        assert!(matcher.matches_argument(&19));
+        assert!(!matcher.matches_argument(&20));
+    }
+
+    #[test]
+    fn in_range_matches_values_in_inclusive_range() {
+        let matcher = Argument::internal_new().in_range(10..=20);
+
+        assert!(matcher.matches_argument(&20));
+        assert!(!matcher.matches_argument(&21));
+    }
+
+    #[test]
+    fn in_range_matches_values_in_unbounded_range() {
+        let matcher = Argument::internal_new().in_range(10..);
+
+        assert!(matcher.matches_argument(&1000));
+        assert!(!matcher.matches_argument(&9));
+    }
+
+    #[cfg(rustc_is_nightly)]
+    #[test]
+    fn comparison_is_displayed_with_operator() {
+        assert_eq!("> 5", Argument::internal_new().gt(5).to_string());
+        assert_eq!(">= 5", Argument::internal_new().ge(5).to_string());
+        assert_eq!("< 5", Argument::internal_new().lt(5).to_string());
+        assert_eq!("<= 5", Argument::internal_new().le(5).to_string());
+    }
+
+    #[cfg(rustc_is_nightly)]
+    #[test]
+    fn range_is_displayed_using_range_syntax() {
+        let argument = Argument::internal_new();
+
+        assert_eq!("in 10..20", argument.in_range(10..20).to_string());
+        assert_eq!("in 10..=20", argument.in_range(10..=20).to_string());
+        assert_eq!("in 10..", argument.in_range(10..).to_string());
+        assert_eq!("in ..20", argument.in_range(..20).to_string());
+        assert_eq!("in ..", argument.in_range::<u32, _>(..).to_string());
+    }
+}
@@ -0,0 +1,97 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use nameof::name_of;
+use regex::Regex;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches strings using the given regular expression.
+    /// Works for arguments of types implementing [`AsRef<str>`], such as `&str` and [`String`].
+    ///
+    /// This matcher requires the `regex` feature.
+    ///
+    /// # Panics
+    /// Panics if the pattern is not a valid regular expression.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Database {
+    ///     fn execute(&self, sql: &str);
+    /// }
+    ///
+    /// let mut database = DatabaseMock::new();
+    /// database
+    ///     .expect_execute(|arg| arg.matches_regex(r"^SELECT .* FROM users"))
+    ///     .returns(());
+    /// database.execute("SELECT id FROM users");
+    /// ```
+    ///
+    /// [`AsRef<str>`]: std::convert::AsRef
+    #[cfg_attr(rustc_is_nightly, doc(cfg(feature = "regex")))]
+    pub fn matches_regex(&self, pattern: &str) -> RegexArgumentMatcher {
+        let regex = Regex::new(pattern)
+            .unwrap_or_else(|error| panic!("Invalid regular expression: {}", error));
+
+        RegexArgumentMatcher { regex }
+    }
+}
+
+pub struct RegexArgumentMatcher {
+    regex: Regex,
+}
+
+impl Display for RegexArgumentMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "matches_regex({:?})", self.regex.as_str())
+    }
+}
+
+impl Debug for RegexArgumentMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type RegexArgumentMatcher))
+            .field(name_of!(regex in Self), &self.regex)
+            .finish()
+    }
+}
+
+impl<U> ArgumentMatcher<U> for RegexArgumentMatcher
+where
+    U: AsRef<str>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        self.regex.is_match(input.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_strings_matching_regex() {
+        let matcher = Argument::internal_new().matches_regex(r"^\d+$");
+
+        assert!(matcher.matches_argument(&"123"));
+        assert!(matcher.matches_argument(&String::from("42")));
+        assert!(!matcher.matches_argument(&"12a"));
+    }
+
+    #[test]
+    fn display_shows_pattern() {
+        let matcher = Argument::internal_new().matches_regex("^foo");
+
+        assert_eq!(r#"matches_regex("^foo")"#, matcher.to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid regular expression")]
+    fn panics_for_invalid_regex() {
+        Argument::internal_new().matches_regex("(");
+    }
+}
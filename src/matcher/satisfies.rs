@@ -0,0 +1,87 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use nameof::name_of;
+use std::borrow::Cow;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches values for which the predicate returns `true`.
+    /// The description is used to describe the matcher in failure messages.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Counter {
+    ///     fn add(&self, value: u32);
+    /// }
+    ///
+    /// let mut counter = CounterMock::new();
+    /// counter
+    ///     .expect_add(|arg| arg.satisfies("is even", |value: &u32| value % 2 == 0))
+    ///     .returns(());
+    /// counter.add(4);
+    /// ```
+    pub fn satisfies<F>(
+        &self,
+        description: impl Into<Cow<'static, str>>,
+        predicate: F,
+    ) -> SatisfiesArgumentMatcher<F> {
+        SatisfiesArgumentMatcher {
+            description: description.into(),
+            predicate,
+        }
+    }
+}
+
+pub struct SatisfiesArgumentMatcher<F> {
+    description: Cow<'static, str>,
+    predicate: F,
+}
+
+impl<F> Display for SatisfiesArgumentMatcher<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl<F> Debug for SatisfiesArgumentMatcher<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type SatisfiesArgumentMatcher<F>))
+            .field(name_of!(description in Self), &self.description)
+            .finish()
+    }
+}
+
+impl<T, F> ArgumentMatcher<T> for SatisfiesArgumentMatcher<F>
+where
+    F: Fn(&T) -> bool,
+{
+    fn matches_argument(&self, input: &T) -> bool {
+        (self.predicate)(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_if_predicate_returns_true() {
+        let matcher = Argument::internal_new().satisfies("is even", |value: &u32| value % 2 == 0);
+
+        assert!(matcher.matches_argument(&4));
+        assert!(!matcher.matches_argument(&5));
+    }
+
+    #[test]
+    fn display_uses_description() {
+        let matcher = Argument::internal_new().satisfies("is even", |value: &u32| value % 2 == 0);
+
+        assert_eq!("is even", matcher.to_string());
+    }
+}
@@ -0,0 +1,245 @@
+use super::ArgumentMatcher;
+use crate::argument::Argument;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+
+impl Argument {
+    /// Creates an argument matcher that matches strings containing the given pattern.
+    /// Works for arguments of types implementing [`AsRef<str>`], such as `&str` and [`String`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Logger {
+    ///     fn log(&self, line: &str);
+    /// }
+    ///
+    /// let mut logger = LoggerMock::new();
+    /// logger.expect_log(|arg| arg.contains("error")).returns(());
+    /// logger.log("an error occurred");
+    /// ```
+    ///
+    /// [`AsRef<str>`]: std::convert::AsRef
+    pub fn contains<T>(&self, pattern: T) -> StringArgumentMatcher<T>
+    where
+        T: AsRef<str>,
+    {
+        StringArgumentMatcher::new(pattern, StringComparison::Contains)
+    }
+
+    /// Creates an argument matcher that matches strings starting with the given prefix.
+    /// Works for arguments of types implementing [`AsRef<str>`], such as `&str` and [`String`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Database {
+    ///     fn execute(&self, sql: String);
+    /// }
+    ///
+    /// let mut database = DatabaseMock::new();
+    /// database
+    ///     .expect_execute(|arg| arg.starts_with("SELECT"))
+    ///     .returns(());
+    /// database.execute(String::from("SELECT * FROM users"));
+    /// ```
+    ///
+    /// [`AsRef<str>`]: std::convert::AsRef
+    pub fn starts_with<T>(&self, prefix: T) -> StringArgumentMatcher<T>
+    where
+        T: AsRef<str>,
+    {
+        StringArgumentMatcher::new(prefix, StringComparison::StartsWith)
+    }
+
+    /// Creates an argument matcher that matches strings ending with the given suffix.
+    /// Works for arguments of types implementing [`AsRef<str>`], such as `&str` and [`String`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Downloader {
+    ///     fn download(&self, url: &str);
+    /// }
+    ///
+    /// let mut downloader = DownloaderMock::new();
+    /// downloader
+    ///     .expect_download(|arg| arg.ends_with(".zip"))
+    ///     .returns(());
+    /// downloader.download("https://example.com/archive.zip");
+    /// ```
+    ///
+    /// [`AsRef<str>`]: std::convert::AsRef
+    pub fn ends_with<T>(&self, suffix: T) -> StringArgumentMatcher<T>
+    where
+        T: AsRef<str>,
+    {
+        StringArgumentMatcher::new(suffix, StringComparison::EndsWith)
+    }
+
+    /// Creates an argument matcher that matches strings that are equal to the given value,
+    /// ignoring ASCII case.
+    /// Works for arguments of types implementing [`AsRef<str>`], such as `&str` and [`String`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Database {
+    ///     fn execute(&self, sql: &str);
+    /// }
+    ///
+    /// let mut database = DatabaseMock::new();
+    /// database
+    ///     .expect_execute(|arg| arg.eq_ignore_ascii_case("commit"))
+    ///     .returns(());
+    /// database.execute("COMMIT");
+    /// ```
+    ///
+    /// [`AsRef<str>`]: std::convert::AsRef
+    pub fn eq_ignore_ascii_case<T>(&self, value: T) -> StringArgumentMatcher<T>
+    where
+        T: AsRef<str>,
+    {
+        StringArgumentMatcher::new(value, StringComparison::EqIgnoreAsciiCase)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum StringComparison {
+    Contains,
+    StartsWith,
+    EndsWith,
+    EqIgnoreAsciiCase,
+}
+
+impl StringComparison {
+    fn name(self) -> &'static str {
+        match self {
+            StringComparison::Contains => "contains",
+            StringComparison::StartsWith => "starts_with",
+            StringComparison::EndsWith => "ends_with",
+            StringComparison::EqIgnoreAsciiCase => "eq_ignore_ascii_case",
+        }
+    }
+}
+
+pub struct StringArgumentMatcher<T>
+where
+    T: AsRef<str>,
+{
+    value: T,
+    comparison: StringComparison,
+}
+
+impl<T> StringArgumentMatcher<T>
+where
+    T: AsRef<str>,
+{
+    fn new(value: T, comparison: StringComparison) -> Self {
+        Self { value, comparison }
+    }
+}
+
+impl<T> Display for StringArgumentMatcher<T>
+where
+    T: AsRef<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({:?})", self.comparison.name(), self.value.as_ref())
+    }
+}
+
+impl<T> Debug for StringArgumentMatcher<T>
+where
+    T: AsRef<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type StringArgumentMatcher<T>))
+            .field(name_of!(value in Self), &self.value.as_ref())
+            .field(name_of!(comparison in Self), &self.comparison)
+            .finish()
+    }
+}
+
+impl<T, U> ArgumentMatcher<U> for StringArgumentMatcher<T>
+where
+    T: AsRef<str>,
+    U: AsRef<str>,
+{
+    fn matches_argument(&self, input: &U) -> bool {
+        let input = input.as_ref();
+        let value = self.value.as_ref();
+
+        match self.comparison {
+            StringComparison::Contains => input.contains(value),
+            StringComparison::StartsWith => input.starts_with(value),
+            StringComparison::EndsWith => input.ends_with(value),
+            StringComparison::EqIgnoreAsciiCase => input.eq_ignore_ascii_case(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn contains_matches_strings_containing_pattern() {
+        let matcher = Argument::internal_new().contains("foo");
+
+        assert!(matcher.matches_argument(&"a foo b"));
+        assert!(matcher.matches_argument(&String::from("foo")));
+        assert!(!matcher.matches_argument(&"bar"));
+    }
+
+    #[test]
+    fn starts_with_matches_strings_starting_with_prefix() {
+        let matcher = Argument::internal_new().starts_with(String::from("foo"));
+
+        assert!(matcher.matches_argument(&"foo bar"));
+        assert!(!matcher.matches_argument(&"bar foo"));
+    }
+
+    #[test]
+    fn ends_with_matches_strings_ending_with_suffix() {
+        let matcher = Argument::internal_new().ends_with("foo");
+
+        assert!(matcher.matches_argument(&"bar foo"));
+        assert!(!matcher.matches_argument(&"foo bar"));
+    }
+
+    #[test]
+    fn eq_ignore_ascii_case_matches_strings_with_different_case() {
+        let matcher = Argument::internal_new().eq_ignore_ascii_case("Foo");
+
+        assert!(matcher.matches_argument(&"FOO"));
+        assert!(!matcher.matches_argument(&"FOOD"));
+    }
+
+    #[test]
+    fn display_shows_comparison_and_value() {
+        let matcher = Argument::internal_new().starts_with("SELECT");
+
+        assert_eq!(r#"starts_with("SELECT")"#, matcher.to_string());
+    }
+}
@@ -1,13 +1,20 @@
 use crate::matcher::ArgumentsMatcher;
-use crate::method_call::{MethodCall, MethodCallBuilder};
+use crate::method_call::{CallOutcome, MethodCall, MethodCallBuilder};
+use crate::recorded_call::RecordedCall;
+use crate::sequence::SequenceError;
+use crate::thread_safety::{SingleThreaded, ThreadSafety};
+use crate::verification_report::{MockVerificationReport, UnsatisfiedExpectation};
 use nameof::name_of;
+use std::borrow::Cow;
 use std::fmt::{self, Debug, Display};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
 
 #[cfg(rustc_is_nightly)]
-const FOOTER: &str = "";
+pub(crate) const FOOTER: &str = "";
 #[cfg(not(rustc_is_nightly))]
-const FOOTER: &str = "\n\n----\nSome mockiato messages may not be displayed correctly. Re-run \
-                      with nightly rust to see full error messages.\n----\n\n";
+pub(crate) const FOOTER: &str = "\n\n----\nSome mockiato messages may not be displayed correctly. \
+                                 Re-run with nightly rust to see full error messages.\n----\n\n";
 
 #[derive(Clone, Debug)]
 enum ExpectedCallOrder {
@@ -16,124 +23,296 @@ enum ExpectedCallOrder {
 }
 
 #[allow(missing_docs)]
-pub struct Method<'mock, A, R>
+pub struct Method<'mock, A, R, T = SingleThreaded>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
-    name: &'static str,
-    calls: Vec<MethodCall<'mock, A, R>>,
+    name: Cow<'static, str>,
+    calls: Vec<MethodCall<'mock, A, R, T>>,
+    /// Called for calls that don't match any expected call
+    stub: Option<MethodCall<'mock, A, R, T>>,
     call_order: ExpectedCallOrder,
+    recorded_calls: Mutex<Vec<RecordedCall>>,
+    /// Number of expected calls that have already been checked by
+    /// [`collect_unsatisfied_expectations`](Self::collect_unsatisfied_expectations)
+    checked_calls: AtomicUsize,
 }
 
-impl<'mock, A, R> Debug for Method<'mock, A, R>
+impl<'mock, A, R, T> Debug for Method<'mock, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct(name_of!(type Method<'mock, A, R>))
+        f.debug_struct(name_of!(type Method<'mock, A, R, T>))
             .field(name_of!(name in Self), &self.name)
             .field(name_of!(calls in Self), &self.calls)
+            .field(name_of!(stub in Self), &self.stub)
             .field(name_of!(call_order in Self), &self.call_order)
+            .field(
+                name_of!(recorded_calls in Self),
+                &*self.lock_recorded_calls(),
+            )
+            .field(name_of!(checked_calls in Self), &self.checked_calls)
             .finish()
     }
 }
 
-impl<'mock, A, R> Clone for Method<'mock, A, R>
+impl<'mock, A, R, T> Clone for Method<'mock, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn clone(&self) -> Self {
         Self {
-            name: self.name,
+            name: self.name.clone(),
             calls: self.calls.clone(),
+            stub: self.stub.clone(),
             call_order: self.call_order.clone(),
+            recorded_calls: Mutex::new(self.recorded_calls()),
+            checked_calls: AtomicUsize::new(self.checked_calls.load(Ordering::SeqCst)),
         }
     }
 }
 
 #[allow(missing_docs)]
-impl<'mock, A, R> Method<'mock, A, R>
+impl<'mock, A, R, T> Method<'mock, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
-    pub fn new(name: &'static str) -> Self {
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
         Self {
-            name,
+            name: name.into(),
             calls: Vec::new(),
+            stub: None,
             call_order: ExpectedCallOrder::Unordered,
+            recorded_calls: Mutex::default(),
+            checked_calls: AtomicUsize::default(),
         }
     }
 
-    pub fn add_expected_call(&mut self, matcher: A) -> MethodCallBuilder<'mock, '_, A, R> {
-        let call = MethodCall::new(matcher);
+    pub fn add_expected_call(&mut self, matcher: A) -> MethodCallBuilder<'mock, '_, A, R, T> {
+        MethodCallBuilder::new(self.push_expected_call(matcher))
+    }
 
-        self.calls.push(call);
+    /// Sets the stub that is called for calls that don't match any expected call.
+    /// The stub may be called any number of times and replaces a previously set stub.
+    pub fn set_stub(&mut self, matcher: A) -> MethodCallBuilder<'mock, '_, A, R, T> {
+        self.stub = Some(MethodCall::new_stub(matcher));
 
-        MethodCallBuilder::new(self.calls.last_mut().unwrap())
+        MethodCallBuilder::new(self.stub.as_mut().unwrap())
     }
 
     pub fn expect_method_calls_in_order(&mut self) {
         self.call_order = ExpectedCallOrder::Sequentially;
     }
 
-    pub fn call_unwrap<'a>(&'a self, arguments: <A as ArgumentsMatcher<'a>>::Arguments) -> R {
+    pub fn call_unwrap<'a>(&self, arguments: <A as ArgumentsMatcher<'a>>::Arguments) -> R {
+        self.unwrap_return_value(self.call_or_default_unwrap(arguments))
+    }
+
+    /// Used for methods that have a default implementation.
+    /// The returned arguments don't borrow from `self`, so that the caller
+    /// is able to pass them to the default implementation.
+    pub fn call_or_default_unwrap<'a>(
+        &self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> CallOutcome<<A as ArgumentsMatcher<'a>>::Arguments, R> {
         self.call(arguments)
             .unwrap_or_else(|err| panic!("\n\n{}{}\n", err, FOOTER))
     }
 
+    /// Used by mocks generated with `#[mockable(nice)]`.
+    /// Calls that don't match any expected call return the default value of `R` instead of panicking.
+    pub fn lenient_call_unwrap<'a>(&self, arguments: <A as ArgumentsMatcher<'a>>::Arguments) -> R
+    where
+        R: Default,
+    {
+        self.unwrap_return_value(self.lenient_call_or_default_unwrap(arguments))
+    }
+
+    /// Lenient counterpart of [`call_or_default_unwrap`].
+    /// Calls that are ambiguous or made out of sequence still panic.
+    ///
+    /// [`call_or_default_unwrap`]: Self::call_or_default_unwrap
+    pub fn lenient_call_or_default_unwrap<'a>(
+        &self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> CallOutcome<<A as ArgumentsMatcher<'a>>::Arguments, R>
+    where
+        R: Default,
+    {
+        match self.call(arguments) {
+            Ok(call_outcome) => call_outcome,
+            Err(CallError::NoMatching(..)) => CallOutcome::Return(R::default()),
+            Err(err) => panic!("\n\n{}{}\n", err, FOOTER),
+        }
+    }
+
     pub fn verify_unwrap(&self) {
         self.verify()
             .unwrap_or_else(|err| panic!("{}{}", err, FOOTER))
     }
 
+    /// Like [`verify_unwrap`], but skips the expected calls that have already been checked by
+    /// [`collect_unsatisfied_expectations`]. Used when the mock is dropped.
+    ///
+    /// [`verify_unwrap`]: Self::verify_unwrap
+    /// [`collect_unsatisfied_expectations`]: Self::collect_unsatisfied_expectations
+    pub fn verify_remaining_unwrap(&self) {
+        self.verify_starting_at(self.checked_calls.load(Ordering::SeqCst))
+            .unwrap_or_else(|err| panic!("{}{}", err, FOOTER))
+    }
+
+    /// Adds the expected calls that were not called the expected number of times to the report.
+    /// The checked expected calls are no longer verified by [`verify_remaining_unwrap`].
+    ///
+    /// [`verify_remaining_unwrap`]: Self::verify_remaining_unwrap
+    pub fn collect_unsatisfied_expectations(&self, report: &mut MockVerificationReport) {
+        self.checked_calls.store(self.calls.len(), Ordering::SeqCst);
+
+        report.extend(
+            self.calls
+                .iter()
+                .filter(|method_call| !method_call.was_called_expected_number_of_times())
+                .map(|method_call| {
+                    UnsatisfiedExpectation::new(
+                        self.name.to_string(),
+                        method_call.matcher().to_string(),
+                        method_call.expected_calls().clone(),
+                        method_call.number_of_calls(),
+                    )
+                }),
+        );
+    }
+
+    /// Returns the calls made to this method, in the order in which they were made.
+    pub fn recorded_calls(&self) -> Vec<RecordedCall> {
+        self.lock_recorded_calls().clone()
+    }
+
+    fn lock_recorded_calls(&self) -> MutexGuard<'_, Vec<RecordedCall>> {
+        // Recording a call can't leave the recorded calls inconsistent,
+        // so the recorded calls of a poisoned lock are used nonetheless.
+        self.recorded_calls
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Removes all expected calls. The configured call order, the stub and the recorded calls are kept.
+    /// The removed calls no longer need to be made for the sequences that they were part of.
+    pub fn reset(&mut self) {
+        for call in self.calls.drain(..) {
+            call.leave_sequence();
+        }
+
+        *self.checked_calls.get_mut() = 0;
+    }
+
+    fn unwrap_return_value<'a>(
+        &self,
+        call_outcome: CallOutcome<<A as ArgumentsMatcher<'a>>::Arguments, R>,
+    ) -> R {
+        match call_outcome {
+            CallOutcome::Return(return_value) => return_value,
+            CallOutcome::CallDefault(_) => {
+                unreachable!("Only methods with a default implementation can call it")
+            }
+        }
+    }
+
+    pub(crate) fn push_expected_call(&mut self, matcher: A) -> &mut MethodCall<'mock, A, R, T> {
+        let call = MethodCall::new(matcher);
+
+        self.calls.push(call);
+
+        self.calls.last_mut().unwrap()
+    }
+
+    /// Adds an expected call that has been configured before it was added.
+    pub(crate) fn push_configured_call(&mut self, call: MethodCall<'mock, A, R, T>) {
+        self.calls.push(call);
+    }
+
     fn call<'a>(
-        &'a self,
+        &self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
-    ) -> Result<R, CallError<'mock, 'a, A, R>> {
-        match self.call_order {
+    ) -> CallResult<'mock, '_, 'a, A, R, T> {
+        let result = match self.call_order {
             ExpectedCallOrder::Sequentially => {
                 self.handle_call_with_sequentially_ordered_expectations(arguments)
             }
             ExpectedCallOrder::Unordered => self.handle_call_with_unordered_expectations(arguments),
+        };
+
+        if let Err(ref error) = result {
+            self.record_call(error.arguments(), None);
         }
+
+        result
+    }
+
+    fn record_call<'a>(
+        &self,
+        arguments: &<A as ArgumentsMatcher<'a>>::Arguments,
+        matched_method_call: Option<&MethodCall<'mock, A, R, T>>,
+    ) {
+        let recorded_call = RecordedCall::new(
+            self.name.to_string(),
+            arguments.to_string(),
+            matched_method_call.map(|method_call| method_call.matcher().to_string()),
+        );
+
+        self.lock_recorded_calls().push(recorded_call);
     }
 
     fn handle_call_with_sequentially_ordered_expectations<'a>(
-        &'a self,
+        &self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
-    ) -> Result<R, CallError<'mock, 'a, A, R>> {
+    ) -> CallResult<'mock, '_, 'a, A, R, T> {
         let matching_method_call = self.calls.iter().find(|call| call.accepts_more_calls());
 
         match matching_method_call {
             Some(matching_method_call)
                 if matching_method_call.matches_expected_arguments(&arguments) =>
             {
-                Ok(matching_method_call.call(arguments))
+                self.call_method_call(matching_method_call, arguments)
             }
-            _ => Err(CallError::NoMatching(arguments, self)),
+            _ => self.call_stub(arguments),
         }
     }
 
     fn handle_call_with_unordered_expectations<'a>(
-        &'a self,
+        &self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
-    ) -> Result<R, CallError<'mock, 'a, A, R>> {
+    ) -> CallResult<'mock, '_, 'a, A, R, T> {
         let matching_method_calls = self
             .calls
             .iter()
             .filter(|call| call.matches_expected_arguments(&arguments))
             .collect::<Vec<_>>();
 
+        // Expected calls that don't accept any more calls are exhausted
+        // and leave the call to the stub, if there is one.
+        let all_matching_method_calls_are_exhausted = matching_method_calls
+            .iter()
+            .all(|method_call| !method_call.accepts_more_calls());
+
         match matching_method_calls.len() {
-            0 => Err(CallError::NoMatching(arguments, self)),
+            0 => self.call_stub(arguments),
             1 => {
                 let expected_call = matching_method_calls.first().unwrap();
                 if expected_call.accepts_more_calls() {
-                    Ok(expected_call.call(arguments))
+                    self.call_method_call(expected_call, arguments)
                 } else {
-                    Err(CallError::NoMatching(arguments, self))
+                    self.call_stub(arguments)
                 }
             }
+            _ if self.stub.is_some() && all_matching_method_calls_are_exhausted => {
+                self.call_stub(arguments)
+            }
             _ => Err(CallError::MoreThanOneMatching(
                 arguments,
                 self,
@@ -142,9 +321,40 @@ where
         }
     }
 
-    fn verify(&self) -> Result<(), VerificationError<'mock, '_, A, R>> {
-        if self
-            .calls
+    fn call_stub<'a>(
+        &self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> CallResult<'mock, '_, 'a, A, R, T> {
+        match self.stub {
+            Some(ref stub) => self.call_method_call(stub, arguments),
+            None => Err(CallError::NoMatching(arguments, self)),
+        }
+    }
+
+    fn call_method_call<'m, 'a>(
+        &'m self,
+        method_call: &'m MethodCall<'mock, A, R, T>,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> CallResult<'mock, 'm, 'a, A, R, T> {
+        match method_call.advance_sequence() {
+            Ok(()) => {
+                self.record_call(&arguments, Some(method_call));
+                Ok(method_call.call(arguments))
+            }
+            Err(error) => Err(CallError::OutOfSequence(arguments, self, error)),
+        }
+    }
+
+    fn verify(&self) -> Result<(), VerificationError<'mock, '_, A, R, T>> {
+        self.verify_starting_at(0)
+    }
+
+    /// Verifies the expected calls starting at the given index.
+    fn verify_starting_at(
+        &self,
+        first_call: usize,
+    ) -> Result<(), VerificationError<'mock, '_, A, R, T>> {
+        if self.calls[first_call..]
             .iter()
             .any(|method_call| !method_call.was_called_expected_number_of_times())
         {
@@ -155,25 +365,52 @@ where
     }
 }
 
+type CallResult<'mock, 'm, 'a, A, R, T> = Result<
+    CallOutcome<<A as ArgumentsMatcher<'a>>::Arguments, R>,
+    CallError<'mock, 'm, 'a, A, R, T>,
+>;
+
+/// `'m` is the lifetime of the borrowed method, `'a` the lifetime of the arguments.
 #[derive(Debug)]
-enum CallError<'mock, 'a, A, R>
+enum CallError<'mock, 'm, 'a, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     NoMatching(
         <A as ArgumentsMatcher<'a>>::Arguments,
-        &'a Method<'mock, A, R>,
+        &'m Method<'mock, A, R, T>,
     ),
     MoreThanOneMatching(
         <A as ArgumentsMatcher<'a>>::Arguments,
-        &'a Method<'mock, A, R>,
-        Vec<&'a MethodCall<'mock, A, R>>,
+        &'m Method<'mock, A, R, T>,
+        Vec<&'m MethodCall<'mock, A, R, T>>,
+    ),
+    OutOfSequence(
+        <A as ArgumentsMatcher<'a>>::Arguments,
+        &'m Method<'mock, A, R, T>,
+        SequenceError,
     ),
 }
 
-impl<'mock, 'a, A, R> Display for CallError<'mock, 'a, A, R>
+impl<'mock, 'm, 'a, A, R, T> CallError<'mock, 'm, 'a, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
+{
+    fn arguments(&self) -> &<A as ArgumentsMatcher<'a>>::Arguments {
+        match self {
+            CallError::NoMatching(arguments, ..)
+            | CallError::MoreThanOneMatching(arguments, ..)
+            | CallError::OutOfSequence(arguments, ..) => arguments,
+        }
+    }
+}
+
+impl<'mock, 'm, 'a, A, R, T> Display for CallError<'mock, 'm, 'a, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -197,21 +434,31 @@ where
                 arguments,
                 DisplayCalls(calls)
             ),
+            CallError::OutOfSequence(arguments, method, error) => {
+                writeln!(
+                    f,
+                    "The call {}{} was made out of sequence.",
+                    method.name, arguments
+                )?;
+                write!(f, "{}", error)
+            }
         }
     }
 }
 
 #[derive(Debug)]
-struct VerificationError<'mock, 'a, A, R>
+struct VerificationError<'mock, 'a, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
-    method: &'a Method<'mock, A, R>,
+    method: &'a Method<'mock, A, R, T>,
 }
 
-impl<'mock, 'a, A, R> Display for VerificationError<'mock, 'a, A, R>
+impl<'mock, 'a, A, R, T> Display for VerificationError<'mock, 'a, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(
@@ -224,15 +471,31 @@ where
             writeln!(f, "{}", call)?;
         }
 
-        Ok(())
+        let recorded_calls = self.method.lock_recorded_calls();
+
+        if recorded_calls.is_empty() {
+            writeln!(f, "No calls to {} were made.", self.method.name)
+        } else {
+            writeln!(f, "The following calls were made:")?;
+
+            for recorded_call in recorded_calls.iter() {
+                writeln!(f, "{}", recorded_call)?;
+            }
+
+            Ok(())
+        }
     }
 }
 
-struct DisplayCalls<'mock, 'a, A, R>(&'a [&'a MethodCall<'mock, A, R>]);
+struct DisplayCalls<'mock, 'a, A, R, T>(&'a [&'a MethodCall<'mock, A, R, T>])
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>;
 
-impl<'mock, 'a, A, R> Display for DisplayCalls<'mock, 'a, A, R>
+impl<'mock, 'a, A, R, T> Display for DisplayCalls<'mock, 'a, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for call in self.0 {
@@ -248,6 +511,7 @@ mod test {
     use super::*;
     use crate::arguments::ArgumentsMock;
     use crate::matcher::ArgumentsMatcherMock;
+    use crate::sequence::Sequence;
 
     #[test]
     fn call_errors_if_more_than_one_call_matches() {
@@ -287,7 +551,10 @@ mod test {
             .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
             .returns(String::from("bar"));
 
-        assert_eq!(String::from("bar"), method.call(ArgumentsMock).unwrap());
+        assert_eq!(
+            String::from("bar"),
+            method.call(ArgumentsMock).unwrap().unwrap_return()
+        );
     }
 
     #[test]
@@ -335,6 +602,218 @@ mod test {
         assert!(method.verify().is_ok());
     }
 
+    #[test]
+    fn collect_unsatisfied_expectations_only_reports_unsatisfied_calls() {
+        let mut method = Method::<_, ()>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
+            .times(..)
+            .returns(());
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .times(2)
+            .returns(());
+
+        method.call(ArgumentsMock).unwrap();
+
+        let mut report = MockVerificationReport::default();
+        method.collect_unsatisfied_expectations(&mut report);
+
+        let unsatisfied_expectations = report.unsatisfied_expectations();
+        assert_eq!(1, unsatisfied_expectations.len());
+        assert_eq!("test", unsatisfied_expectations[0].method_name());
+        assert_eq!("mock", unsatisfied_expectations[0].call());
+        assert_eq!(1, unsatisfied_expectations[0].number_of_calls());
+    }
+
+    #[test]
+    fn lenient_call_returns_default_value_if_no_calls_match() {
+        let mut method = Method::<_, String>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
+            .times(..)
+            .returns(String::from("bar"));
+
+        assert_eq!(String::new(), method.lenient_call_unwrap(ArgumentsMock));
+        assert_eq!(None, method.recorded_calls()[0].matched_expected_call());
+    }
+
+    #[test]
+    fn lenient_call_calls_matching_method_call() {
+        let mut method = Method::<_, String>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(String::from("bar"));
+
+        assert_eq!(
+            String::from("bar"),
+            method.lenient_call_unwrap(ArgumentsMock)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "matches more than one expected call")]
+    fn lenient_call_panics_if_more_than_one_call_matches() {
+        let mut method = Method::<_, ()>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .times(..)
+            .returns(());
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .times(..)
+            .returns(());
+
+        method.lenient_call_unwrap(ArgumentsMock);
+    }
+
+    #[test]
+    fn stub_is_called_if_no_calls_match() {
+        let mut method = Method::<_, String>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
+            .times(..)
+            .returns(String::from("expected"));
+        method
+            .set_stub(ArgumentsMatcherMock::new(None))
+            .returns(String::from("stub"));
+
+        assert_eq!(
+            String::from("stub"),
+            method.call(ArgumentsMock).unwrap().unwrap_return()
+        );
+        assert_eq!(
+            String::from("stub"),
+            method.call(ArgumentsMock).unwrap().unwrap_return()
+        );
+    }
+
+    #[test]
+    fn stub_is_not_called_if_a_call_matches() {
+        let mut method = Method::<_, String>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(String::from("expected"));
+        method
+            .set_stub(ArgumentsMatcherMock::new(None))
+            .returns(String::from("stub"));
+
+        assert_eq!(
+            String::from("expected"),
+            method.call(ArgumentsMock).unwrap().unwrap_return()
+        );
+    }
+
+    #[test]
+    fn stub_is_called_if_all_matching_calls_are_exhausted() {
+        let mut method = Method::<_, String>::new("test");
+
+        for _ in 0..2 {
+            method
+                .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+                .times(0)
+                .returns(String::from("expected"));
+        }
+        method
+            .set_stub(ArgumentsMatcherMock::new(None))
+            .returns(String::from("stub"));
+
+        assert_eq!(
+            String::from("stub"),
+            method.call(ArgumentsMock).unwrap().unwrap_return()
+        );
+    }
+
+    #[test]
+    fn stub_is_not_verified() {
+        let mut method = Method::<ArgumentsMatcherMock, ()>::new("test");
+
+        method.set_stub(ArgumentsMatcherMock::new(None)).returns(());
+
+        assert!(method.verify().is_ok());
+    }
+
+    #[test]
+    fn call_is_recorded_with_matched_expected_call() {
+        let mut method = Method::<_, ()>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(());
+
+        method.call(ArgumentsMock).unwrap();
+
+        let recorded_calls = method.recorded_calls();
+        assert_eq!(1, recorded_calls.len());
+        assert_eq!("test", recorded_calls[0].method_name());
+        assert_eq!("mock", recorded_calls[0].arguments());
+        assert_eq!(Some("mock"), recorded_calls[0].matched_expected_call());
+    }
+
+    #[test]
+    fn unexpected_call_is_recorded() {
+        let method = Method::<ArgumentsMatcherMock, ()>::new("test");
+
+        assert!(method.call(ArgumentsMock).is_err());
+
+        let recorded_calls = method.recorded_calls();
+        assert_eq!(1, recorded_calls.len());
+        assert_eq!(None, recorded_calls[0].matched_expected_call());
+    }
+
+    #[test]
+    fn verification_error_shows_recorded_calls() {
+        let mut method = Method::<_, ()>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .times(2)
+            .returns(());
+
+        method.call(ArgumentsMock).unwrap();
+
+        let error = method.verify().unwrap_err().to_string();
+        assert!(error.ends_with("The following calls were made:\ntestmock, matched mock\n"));
+    }
+
+    #[test]
+    fn reset_removes_expected_calls() {
+        let mut method = Method::<_, ()>::new("test");
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(None))
+            .returns(());
+        method.reset();
+
+        assert!(method.verify().is_ok());
+        assert!(method.call(ArgumentsMock).is_err());
+    }
+
+    #[test]
+    fn reset_removes_expected_calls_from_sequence() {
+        let sequence = Sequence::new();
+        let mut first_method = Method::<_, ()>::new("first");
+        let mut second_method = Method::<_, ()>::new("second");
+
+        first_method
+            .add_expected_call(ArgumentsMatcherMock::new(None))
+            .in_sequence(&sequence)
+            .returns(());
+        first_method.reset();
+        second_method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .in_sequence(&sequence)
+            .returns(());
+
+        assert!(second_method.call(ArgumentsMock).is_ok());
+    }
+
     #[test]
     fn unordered_expectations_work_with_one_matching_expected_call() {
         let mut method = Method::<_, ()>::new("test");
@@ -381,6 +860,27 @@ mod test {
         assert!(result.is_err())
     }
 
+    #[test]
+    fn call_errors_if_call_is_out_of_sequence() {
+        let sequence = Sequence::new();
+        let mut first_method = Method::<_, ()>::new("first");
+        let mut second_method = Method::<_, ()>::new("second");
+
+        first_method
+            .add_expected_call(ArgumentsMatcherMock::new(None))
+            .in_sequence(&sequence)
+            .returns(());
+        second_method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .in_sequence(&sequence)
+            .returns(());
+
+        match second_method.call(ArgumentsMock) {
+            Err(CallError::OutOfSequence(..)) => {}
+            _ => panic!("unexpected result from method call"),
+        }
+    }
+
     #[test]
     fn ordered_expectations_use_first_matching_call_regardless_of_other_expected_calls() {
         let mut method = Method::<_, ()>::new("test");
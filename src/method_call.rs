@@ -1,71 +1,70 @@
 use crate::default_return_value::DefaultReturnValue;
 use crate::expected_calls::ExpectedCalls;
 use crate::fmt::{DisplayOption, DisplayTimes};
-use crate::matcher::ArgumentsMatcher;
-use crate::return_value::{self, ReturnValueGenerator};
+use crate::matcher::{ArgumentsMatcher, HasDefaultImpl};
+use crate::return_value;
+use crate::sequence::{Sequence, SequenceError, SequenceStep};
+use crate::thread_safety::{SingleThreaded, ThreadSafe, ThreadSafety};
 use nameof::name_of;
-use std::cell::RefCell;
 use std::fmt::{self, Debug, Display};
+use std::marker::PhantomData;
+use std::ops::DerefMut;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 /// Configures an expected method call.
 /// This builder is returned from the `expect_*` methods on a generated mock.
-pub struct MethodCallBuilder<'mock, 'a, A, R>
+///
+/// The values passed to a builder of a mock generated with `#[mockable(sync)]`
+/// need to be [`Send`] and [`Sync`].
+pub struct MethodCallBuilder<'mock, 'a, A, R, T = SingleThreaded>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
-    call: &'a mut MethodCall<'mock, A, R>,
+    call: Box<dyn DerefMut<Target = MethodCall<'mock, A, R, T>> + 'a>,
 }
 
-impl<'mock, 'a, A, R> Debug for MethodCallBuilder<'mock, 'a, A, R>
+impl<'mock, 'a, A, R, T> Debug for MethodCallBuilder<'mock, 'a, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct(name_of!(type MethodCallBuilder<'mock, 'a, A, R>))
-            .field(name_of!(call in Self), &self.call)
+        f.debug_struct(name_of!(type MethodCallBuilder<'mock, 'a, A, R, T>))
+            .field(name_of!(call in Self), &**self.call)
             .finish()
     }
 }
 
-impl<'mock, 'a, A, R> MethodCallBuilder<'mock, 'a, A, R>
+impl<'mock, 'a, A, R, T> MethodCallBuilder<'mock, 'a, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
-    /// Defines the return value for this method.
-    /// The value must be [`Clone`]able.
-    ///
-    /// This method does not need to be called on nightly for the unit type `()`.
-    pub fn returns(&mut self, return_value: R) -> &mut Self
-    where
-        R: Clone + 'mock,
-    {
-        self.call.return_value = Some(Rc::new(return_value::Cloned(return_value)));
-        self
-    }
-
-    /// Defines a return value for this method that will be returned once.
-    /// The mocked method will panic on subsequent calls.
-    ///
-    /// This method does not need to be called on nightly for the unit type `()`.
-    pub fn returns_once(&mut self, return_value: R) -> &mut Self
-    where
-        R: 'mock,
-    {
-        self.call.return_value = Some(Rc::new(return_value::Once::new(return_value)));
-        self.assert_times_and_return_value_are_compatible();
-        self
-    }
-
     /// Defines that this method panics.
     pub fn panics(&mut self) -> &mut Self {
-        self.call.return_value = Some(Rc::new(return_value::Panic(None)));
+        self.call.return_value = Some(T::store_return_value_generator(return_value::Panic(None)));
         self
     }
 
     /// Defines that this method panics with a message.
     pub fn panics_with_message(&mut self, message: &'static str) -> &mut Self {
-        self.call.return_value = Some(Rc::new(return_value::Panic(Some(message))));
+        self.call.return_value = Some(T::store_return_value_generator(return_value::Panic(Some(
+            message,
+        ))));
+        self
+    }
+
+    /// Adds this expected call as the next step of the given [`Sequence`].
+    /// The calls in a sequence have to be made in the order in which they were added.
+    pub fn in_sequence(&mut self, sequence: &Sequence) -> &mut Self {
+        let step = sequence.add_step(
+            self.call.matcher.to_string(),
+            self.call.expected_calls.clone(),
+        );
+        self.call.sequence_step = Some(step);
         self
     }
 
@@ -94,105 +93,325 @@ where
         E: Into<ExpectedCalls>,
     {
         self.call.expected_calls = expected_calls.into();
+        if let Some(ref step) = self.call.sequence_step {
+            step.set_expected_calls(self.call.expected_calls.clone());
+        }
         self.assert_times_and_return_value_are_compatible();
         self
     }
 
-    pub(crate) fn new(call: &'a mut MethodCall<'mock, A, R>) -> Self {
-        Self { call }
+    pub(crate) fn new(call: impl DerefMut<Target = MethodCall<'mock, A, R, T>> + 'a) -> Self {
+        Self {
+            call: Box::new(call),
+        }
     }
 
     fn assert_times_and_return_value_are_compatible(&self) {
-        let one_expected_call = ExpectedCalls::from(1);
-        let returns_only_once = self
-            .call
-            .return_value
-            .as_ref()
-            .map(|r| !r.can_return_more_than_once())
-            .unwrap_or_default();
+        let return_value = match self.call.return_value {
+            Some(ref return_value) => T::return_value_generator(return_value),
+            None => return,
+        };
         let expected_calls = &self.call.expected_calls;
-        if returns_only_once && expected_calls != &one_expected_call {
+
+        let one_expected_call = ExpectedCalls::from(1);
+        if !return_value.can_return_more_than_once() && expected_calls != &one_expected_call {
             panic!(
                 "Return value can only be returned once but call was expected {}.",
                 expected_calls
             );
         }
+
+        if let Some(max_number_of_return_values) = return_value.max_number_of_return_values() {
+            let return_values = match max_number_of_return_values {
+                1 => String::from("Return value can only be returned once"),
+                _ => format!(
+                    "Return values can only be returned {}",
+                    DisplayTimes(max_number_of_return_values)
+                ),
+            };
+
+            if expected_calls.min_value() > max_number_of_return_values {
+                panic!(
+                    "{} but call was expected {}.",
+                    return_values, expected_calls
+                );
+            }
+
+            match expected_calls.max_value() {
+                None => panic!(
+                    "{} but call was expected {}. The number of expected calls needs an upper \
+                     bound, e.g. `times(..={})`.",
+                    return_values, expected_calls, max_number_of_return_values
+                ),
+                Some(max_number_of_calls) if max_number_of_calls > max_number_of_return_values => {
+                    panic!(
+                        "{} but call was expected {}.",
+                        return_values, expected_calls
+                    )
+                }
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+impl<'mock, 'a, A, R, T> MethodCallBuilder<'mock, 'a, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args> + HasDefaultImpl,
+    T: ThreadSafety<'mock, A, R>,
+{
+    /// Defines that the default implementation of this method, as provided by the trait, is called.
+    /// This is only available for methods that have a default implementation.
+    pub fn calls_default(&mut self) -> &mut Self {
+        self.call.return_value = Some(T::store_return_value_generator(return_value::CallDefault));
+        self
     }
 }
 
-pub(crate) struct MethodCall<'mock, A, R> {
+/// Implements the methods that store values passed by the user.
+/// The values of thread safe mocks are stored in an [`Arc`] and need to be [`Send`] and [`Sync`].
+macro_rules! impl_storing_methods {
+    ($thread_safety:ty, $pointer:ident, $($bounds:tt)*) => {
+        impl<'mock, 'a, A, R> MethodCallBuilder<'mock, 'a, A, R, $thread_safety>
+        where
+            A: for<'args> ArgumentsMatcher<'args>,
+        {
+            /// Defines the return value for this method.
+            /// The value must be [`Clone`]able.
+            ///
+            /// This method does not need to be called on nightly for the unit type `()`.
+            pub fn returns(&mut self, return_value: R) -> &mut Self
+            where
+                R: Clone + 'mock $($bounds)*,
+            {
+                self.call.return_value = Some($pointer::new(return_value::Cloned(return_value)));
+                self
+            }
+
+            /// Defines a closure that computes the return value of this method from the arguments of a call.
+            /// The closure is called with the arguments struct of the method,
+            /// which has a field for every argument (e.g. `arguments.name`).
+            ///
+            /// In contrast to [`returns`], the return value doesn't need to be [`Clone`]able.
+            ///
+            /// [`returns`]: Self::returns
+            pub fn returns_with<F>(&mut self, return_value: F) -> &mut Self
+            where
+                F: for<'args> Fn(<A as ArgumentsMatcher<'args>>::Arguments) -> R + 'mock $($bounds)*,
+            {
+                self.call.return_value = Some($pointer::new(return_value::Computed(return_value)));
+                self
+            }
+
+            /// Defines a side effect that is applied to the arguments of a call before the return value is produced.
+            /// This allows writing to arguments that are mutable references (e.g. buffers passed to `read`).
+            ///
+            /// Multiple effects are applied in the order in which they were defined.
+            pub fn with_effect<F>(&mut self, effect: F) -> &mut Self
+            where
+                F: for<'args> Fn(&mut <A as ArgumentsMatcher<'args>>::Arguments) + 'mock $($bounds)*,
+            {
+                self.call.effects.push($pointer::new(effect));
+                self
+            }
+
+            /// Defines a return value for this method that will be returned once.
+            /// The mocked method will panic on subsequent calls.
+            ///
+            /// This method does not need to be called on nightly for the unit type `()`.
+            pub fn returns_once(&mut self, return_value: R) -> &mut Self
+            where
+                R: 'mock $($bounds)*,
+            {
+                self.call.return_value = Some($pointer::new(return_value::Once::new(return_value)));
+                self.assert_times_and_return_value_are_compatible();
+                self
+            }
+
+            /// Defines return values for this method that will be returned in the given order, each of them once.
+            /// The mocked method will panic once all values have been returned.
+            ///
+            /// The call must not be expected more often than there are values,
+            /// which requires an upper bound on the number of expected calls (e.g. `times(1..=2)`).
+            pub fn returns_in_order(&mut self, return_values: Vec<R>) -> &mut Self
+            where
+                R: 'mock $($bounds)*,
+            {
+                self.call.return_value =
+                    Some($pointer::new(return_value::InOrder::new(return_values)));
+                self.assert_times_and_return_value_are_compatible();
+                self
+            }
+        }
+    };
+}
+
+impl_storing_methods!(SingleThreaded, Rc,);
+impl_storing_methods!(ThreadSafe, Arc, + Send + Sync);
+
+/// The outcome of a call to a mocked method.
+#[derive(Debug)]
+pub enum CallOutcome<A, R> {
+    /// The value that the mocked method returns
+    Return(R),
+    /// The mocked method calls its default implementation with the given arguments
+    CallDefault(A),
+}
+
+impl<A, R> CallOutcome<A, R> {
+    #[cfg(test)]
+    pub(crate) fn unwrap_return(self) -> R {
+        match self {
+            CallOutcome::Return(return_value) => return_value,
+            CallOutcome::CallDefault(_) => panic!("The call was expected to return a value"),
+        }
+    }
+}
+
+pub(crate) struct MethodCall<'mock, A, R, T = SingleThreaded>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
+{
     expected_calls: ExpectedCalls,
-    actual_number_of_calls: RefCell<u64>,
-    matcher: Rc<A>,
-    return_value: Option<Rc<dyn ReturnValueGenerator<A, R> + 'mock>>,
+    actual_number_of_calls: AtomicU64,
+    matcher: T::Matcher,
+    return_value: Option<T::ReturnValueGenerator>,
+    effects: Vec<T::Effect>,
+    sequence_step: Option<SequenceStep>,
+    thread_safety: PhantomData<T>,
 }
 
-impl<'mock, A, R> Debug for MethodCall<'mock, A, R>
+impl<'mock, A, R, T> Debug for MethodCall<'mock, A, R, T>
 where
-    A: Debug,
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct(name_of!(type MethodCall<'mock, A, R>))
+        f.debug_struct(name_of!(type MethodCall<'mock, A, R, T>))
             .field(name_of!(expected_calls in Self), &self.expected_calls)
             .field(
                 name_of!(actual_number_of_calls in Self),
                 &self.actual_number_of_calls,
             )
-            .field(name_of!(matcher in Self), &self.matcher)
-            .field(name_of!(return_value in Self), &self.return_value)
+            .field(name_of!(matcher in Self), &*self.matcher)
+            .field(
+                name_of!(return_value in Self),
+                &self.return_value.as_ref().map(T::return_value_generator),
+            )
+            .field(name_of!(effects in Self), &self.effects.len())
+            .field(name_of!(sequence_step in Self), &self.sequence_step)
             .finish()
     }
 }
 
-impl<'mock, A, R> Clone for MethodCall<'mock, A, R>
+impl<'mock, A, R, T> Clone for MethodCall<'mock, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn clone(&self) -> Self {
         Self {
             expected_calls: self.expected_calls.clone(),
-            actual_number_of_calls: self.actual_number_of_calls.clone(),
+            actual_number_of_calls: AtomicU64::new(self.number_of_calls()),
             matcher: self.matcher.clone(),
             return_value: self.return_value.clone(),
+            effects: self.effects.clone(),
+            sequence_step: self.sequence_step.clone(),
+            thread_safety: PhantomData,
         }
     }
 }
 
-impl<'mock, A, R> MethodCall<'mock, A, R>
+impl<'mock, A, R, T> MethodCall<'mock, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     pub(crate) fn new(matcher: A) -> Self {
         Self {
             expected_calls: ExpectedCalls::default(),
-            actual_number_of_calls: RefCell::default(),
-            matcher: Rc::new(matcher),
-            return_value: R::default_return_value(),
+            actual_number_of_calls: AtomicU64::default(),
+            matcher: T::store_matcher(matcher),
+            return_value: R::default_return_value::<T>(),
+            effects: Vec::new(),
+            sequence_step: None,
+            thread_safety: PhantomData,
         }
     }
 
-    pub(crate) fn call(&self, arguments: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
-        *self.actual_number_of_calls.borrow_mut() += 1;
+    /// Creates a stub, which is a call that may be called any number of times.
+    pub(crate) fn new_stub(matcher: A) -> Self {
+        Self {
+            expected_calls: (..).into(),
+            ..Self::new(matcher)
+        }
+    }
+
+    pub(crate) fn call<'a>(
+        &self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> CallOutcome<<A as ArgumentsMatcher<'a>>::Arguments, R> {
+        self.actual_number_of_calls.fetch_add(1, Ordering::SeqCst);
+
+        self.matcher.on_call(&arguments);
 
-        match self.return_value {
-            Some(ref return_value) => return_value.generate_return_value(arguments),
+        let mut arguments = arguments;
+        for effect in &self.effects {
+            T::effect(effect).apply(&mut arguments);
+        }
+
+        match self.return_value.as_ref().map(T::return_value_generator) {
+            Some(return_value) if return_value.calls_default() => {
+                CallOutcome::CallDefault(arguments)
+            }
+            Some(return_value) => {
+                CallOutcome::Return(return_value.generate_return_value(arguments))
+            }
             None => panic!("No return value was specified"),
         }
     }
 
+    /// Advances the sequence that this call is part of, if any.
+    /// This needs to happen before the call is made.
+    pub(crate) fn advance_sequence(&self) -> Result<(), SequenceError> {
+        match self.sequence_step {
+            Some(ref step) => step.call(),
+            None => Ok(()),
+        }
+    }
+
+    /// Removes this call from the sequence that it is part of, if any.
+    pub(crate) fn leave_sequence(&self) {
+        if let Some(ref step) = self.sequence_step {
+            step.remove();
+        }
+    }
+
     pub(crate) fn was_called_expected_number_of_times(&self) -> bool {
-        self.expected_calls
-            .contains(*self.actual_number_of_calls.borrow())
+        self.expected_calls.contains(self.number_of_calls())
     }
 
     pub(crate) fn accepts_more_calls(&self) -> bool {
-        let number_of_calls = *self.actual_number_of_calls.borrow();
+        let number_of_calls = self.number_of_calls();
         match self.expected_calls.max_value() {
             Some(max_value) => number_of_calls < max_value,
             None => true,
         }
     }
 
+    pub(crate) fn matcher(&self) -> &A {
+        &self.matcher
+    }
+
+    pub(crate) fn expected_calls(&self) -> &ExpectedCalls {
+        &self.expected_calls
+    }
+
+    pub(crate) fn number_of_calls(&self) -> u64 {
+        self.actual_number_of_calls.load(Ordering::SeqCst)
+    }
+
     pub(crate) fn matches_expected_arguments<'a>(
         &self,
         arguments: &<A as ArgumentsMatcher<'a>>::Arguments,
@@ -201,18 +420,19 @@ where
     }
 }
 
-impl<'mock, A, R> Display for MethodCall<'mock, A, R>
+impl<'mock, A, R, T> Display for MethodCall<'mock, A, R, T>
 where
     A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'mock, A, R>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "{} -> {} {}, was called {}",
-            self.matcher,
-            DisplayOption(self.return_value.as_ref()),
+            *self.matcher,
+            DisplayOption(self.return_value.as_ref().map(T::return_value_generator)),
             self.expected_calls,
-            DisplayTimes(*self.actual_number_of_calls.borrow())
+            DisplayTimes(self.number_of_calls())
         )
     }
 }
@@ -222,6 +442,7 @@ mod test {
     use super::*;
     use crate::arguments::ArgumentsMock;
     use crate::matcher::ArgumentsMatcherMock;
+    use crate::return_value::ReturnValueGenerator;
     use std::cell::RefCell;
     use std::fmt::Debug;
     use std::thread::panicking;
@@ -301,11 +522,42 @@ mod test {
             "foo",
         )))));
 
-        let return_value = call.call(ArgumentsMock);
+        let return_value = call.call(ArgumentsMock).unwrap_return();
 
         assert_eq!(String::from("foo"), return_value);
     }
 
+    #[test]
+    fn call_returns_arguments_if_default_is_called() {
+        let mut call: MethodCall<'_, _, String> = MethodCall::new(ArgumentsMatcherMock::new(None));
+        call.return_value = Some(Rc::new(return_value::CallDefault));
+
+        match call.call(ArgumentsMock) {
+            CallOutcome::CallDefault(ArgumentsMock) => {}
+            CallOutcome::Return(_) => panic!("unexpected return value"),
+        }
+
+        assert!(call.was_called_expected_number_of_times());
+    }
+
+    #[test]
+    fn call_applies_effects_before_generating_return_value() {
+        let applied_effects = Arc::new(AtomicU64::default());
+        let mut call: MethodCall<'_, _, u64> = MethodCall::new(ArgumentsMatcherMock::new(None));
+        call.effects.push(Rc::new({
+            let applied_effects = applied_effects.clone();
+            move |_: &mut ArgumentsMock| {
+                applied_effects.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+        call.return_value = Some(Rc::new(return_value::Computed({
+            let applied_effects = applied_effects.clone();
+            move |_| applied_effects.load(Ordering::SeqCst)
+        })));
+
+        assert_eq!(1, call.call(ArgumentsMock).unwrap_return());
+    }
+
     #[test]
     fn was_called_expected_number_of_times_returns_true() {
         let mut call: MethodCall<'_, _, ()> = MethodCall::new(ArgumentsMatcherMock::new(None));
@@ -322,8 +574,8 @@ mod test {
 
     #[test]
     fn was_called_expected_number_of_times_returns_false() {
-        let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(None));
+        let call = {
+            let mut call: MethodCall<'_, _, ()> = MethodCall::new(ArgumentsMatcherMock::new(None));
             call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(Some(()))));
             call.expected_calls = (2..).into();
             call
@@ -336,8 +588,9 @@ mod test {
 
     #[test]
     fn matches_expected_arguments_returns_true() {
-        let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(Some(true)));
+        let call = {
+            let mut call: MethodCall<'_, _, ()> =
+                MethodCall::new(ArgumentsMatcherMock::new(Some(true)));
             call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(None)));
             call
         };
@@ -347,8 +600,9 @@ mod test {
 
     #[test]
     fn matches_expected_arguments_returns_false() {
-        let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(Some(false)));
+        let call = {
+            let mut call: MethodCall<'_, _, ()> =
+                MethodCall::new(ArgumentsMatcherMock::new(Some(false)));
             call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(None)));
             call
         };
@@ -0,0 +1,158 @@
+use crate::recorded_call::RecordedCall;
+use crate::verification_report::MockVerificationReport;
+
+/// Implemented by the mocks generated using [`mockable`](crate::mockable).
+///
+/// The operations on a whole mock are exposed as free functions (e.g. [`checkpoint`])
+/// instead of inherent methods, so that they can't collide with the methods of the mocked trait.
+pub trait Mock {
+    /// Verifies and removes the expected calls of all methods, including the ones of supertrait mocks.
+    fn checkpoint(&mut self);
+
+    /// Adds the unsatisfied expected calls of all methods, including the ones of supertrait mocks,
+    /// to the report. The checked expected calls are no longer verified when the mock is dropped.
+    fn collect_unsatisfied_expectations(&self, report: &mut MockVerificationReport);
+
+    /// Adds the recorded calls of all methods, including the ones of supertrait mocks, to `calls`.
+    fn collect_recorded_calls(&self, calls: &mut Vec<RecordedCall>);
+}
+
+/// Returns the calls made to the mock, in the order in which they were made.
+/// This includes the calls made to supertrait mocks.
+///
+/// The calls of a single method are returned by the generated `calls_to_<method_name>`
+/// associated function of the mock, e.g. `GreeterMock::calls_to_greet(&greeter)`.
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+///
+///     fn wave(&self);
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter.expect_wave().returns(());
+/// greeter
+///     .expect_greet(|arg| arg.any())
+///     .returns(String::from("Hello"));
+///
+/// greeter.wave();
+/// greeter.greet("Jane");
+///
+/// let method_names: Vec<_> = mockiato::calls(&greeter)
+///     .iter()
+///     .map(|call| call.method_name().to_string())
+///     .collect();
+///
+/// assert_eq!(
+///     vec!["GreeterMock::wave", "GreeterMock::greet"],
+///     method_names
+/// );
+/// ```
+pub fn calls<M>(mock: &M) -> Vec<RecordedCall>
+where
+    M: Mock,
+{
+    let mut calls = Vec::new();
+
+    mock.collect_recorded_calls(&mut calls);
+
+    calls.sort_by_key(RecordedCall::sequence_number);
+    calls
+}
+
+/// Verifies that all expected calls of the mock have been made, without panicking.
+/// This includes the expected calls of supertrait mocks.
+///
+/// The mock is consumed, so its expected calls are not verified again when it is dropped.
+///
+/// # Errors
+/// Returns a report listing all expected calls that were not called the expected number of times.
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter
+///     .expect_greet(|arg| arg.partial_eq("Jane"))
+///     .times(2)
+///     .returns(String::from("Hello Jane"));
+///
+/// greeter.greet("Jane");
+///
+/// let report = mockiato::verify(greeter).unwrap_err();
+///
+/// assert_eq!(1, report.unsatisfied_expectations().len());
+/// ```
+pub fn verify<M>(mock: M) -> Result<(), MockVerificationReport>
+where
+    M: Mock,
+{
+    let mut report = MockVerificationReport::default();
+
+    mock.collect_unsatisfied_expectations(&mut report);
+
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(report)
+    }
+}
+
+/// Verifies that all expected calls of the mock have been made and removes them,
+/// so that new expectations can be configured.
+/// This includes the expected calls of supertrait mocks.
+///
+/// # Panics
+/// Panics if an expected call was not called the expected number of times.
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter
+///     .expect_greet(|arg| arg.partial_eq("Doe"))
+///     .returns(String::from("Hello Doe"));
+/// assert_eq!("Hello Doe", greeter.greet("Doe"));
+///
+/// mockiato::checkpoint(&mut greeter);
+///
+/// greeter
+///     .expect_greet(|arg| arg.partial_eq("Doe"))
+///     .returns(String::from("Goodbye Doe"));
+/// assert_eq!("Goodbye Doe", greeter.greet("Doe"));
+/// ```
+pub fn checkpoint<M>(mock: &mut M)
+where
+    M: Mock,
+{
+    mock.checkpoint();
+}
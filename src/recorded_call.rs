@@ -0,0 +1,133 @@
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Orders the recorded calls of all mocks.
+static NEXT_SEQUENCE_NUMBER: AtomicU64 = AtomicU64::new(0);
+
+/// A call that was made to a mocked method.
+/// The calls are returned by [`calls`](crate::calls) and the `calls_to_<method_name>`
+/// associated functions of a generated mock.
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter
+///     .expect_greet(|arg| arg.any())
+///     .returns(String::from("Hello"));
+///
+/// greeter.greet("Jane");
+///
+/// let calls = GreeterMock::calls_to_greet(&greeter);
+///
+/// assert_eq!(1, calls.len());
+/// assert_eq!("GreeterMock::greet", calls[0].method_name());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RecordedCall {
+    method_name: String,
+    arguments: String,
+    matched_expected_call: Option<String>,
+    sequence_number: u64,
+}
+
+impl RecordedCall {
+    pub(crate) fn new(
+        method_name: String,
+        arguments: String,
+        matched_expected_call: Option<String>,
+    ) -> Self {
+        Self {
+            method_name,
+            arguments,
+            matched_expected_call,
+            sequence_number: NEXT_SEQUENCE_NUMBER.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+
+    /// The name of the mocked method, e.g. `GreeterMock::greet`.
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    /// The arguments of the call, formatted using [`Debug`] where available, e.g. `("Jane")`.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    pub fn arguments(&self) -> &str {
+        &self.arguments
+    }
+
+    /// The expected call that the call was matched to, e.g. `greet("Jane")`.
+    /// `None` if the call was not expected.
+    pub fn matched_expected_call(&self) -> Option<&str> {
+        self.matched_expected_call.as_deref()
+    }
+
+    /// Increases with every call made to any mock.
+    /// Sorting calls by this number orders them by the time they were made.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+}
+
+impl Display for RecordedCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.method_name, self.arguments)?;
+
+        match self.matched_expected_call {
+            Some(ref matched_expected_call) => write!(f, ", matched {}", matched_expected_call),
+            None => write!(f, ", not expected"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sequence_numbers_increase() {
+        let first = RecordedCall::new(String::from("first"), String::from("()"), None);
+        let second = RecordedCall::new(String::from("second"), String::from("()"), None);
+
+        assert!(first.sequence_number() < second.sequence_number());
+    }
+
+    #[test]
+    fn display_shows_matched_expected_call() {
+        let call = RecordedCall::new(
+            String::from("GreeterMock::greet"),
+            String::from("(\"Jane\")"),
+            Some(String::from("greet(*)")),
+        );
+
+        assert_eq!(
+            "GreeterMock::greet(\"Jane\"), matched greet(*)",
+            call.to_string()
+        );
+    }
+
+    #[test]
+    fn display_shows_unexpected_call() {
+        let call = RecordedCall::new(
+            String::from("GreeterMock::greet"),
+            String::from("(\"Jane\")"),
+            None,
+        );
+
+        assert_eq!(
+            "GreeterMock::greet(\"Jane\"), not expected",
+            call.to_string()
+        );
+    }
+}
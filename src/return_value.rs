@@ -1,15 +1,21 @@
+pub(crate) use self::call_default::*;
 pub(crate) use self::cloned::*;
+pub(crate) use self::computed::*;
+pub(crate) use self::in_order::*;
 pub(crate) use self::once::*;
 pub(crate) use self::panic::*;
 
 use crate::matcher::ArgumentsMatcher;
 use std::fmt::{Debug, Display};
 
+mod call_default;
 mod cloned;
+mod computed;
+mod in_order;
 mod once;
 mod panic;
 
-pub(crate) trait ReturnValueGenerator<A, R>: Display + Debug
+pub trait ReturnValueGenerator<A, R>: Display + Debug
 where
     A: for<'args> ArgumentsMatcher<'args>,
 {
@@ -18,4 +24,15 @@ where
     fn can_return_more_than_once(&self) -> bool {
         true
     }
+
+    /// How many values can be generated. `None` if there's no limit.
+    fn max_number_of_return_values(&self) -> Option<u64> {
+        None
+    }
+
+    /// Whether the mock should call the default implementation of the method
+    /// instead of generating a return value.
+    fn calls_default(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,27 @@
+use super::ReturnValueGenerator;
+use crate::matcher::ArgumentsMatcher;
+use std::fmt::{self, Display};
+
+/// Marks a call that is handled by the default implementation of the mocked method.
+/// The mock calls the default implementation itself, which is why this never generates a value.
+#[derive(Debug)]
+pub(crate) struct CallDefault;
+
+impl Display for CallDefault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "default implementation")
+    }
+}
+
+impl<A, R> ReturnValueGenerator<A, R> for CallDefault
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn generate_return_value(&self, _: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        unreachable!("The default implementation is called by the mock")
+    }
+
+    fn calls_default(&self) -> bool {
+        true
+    }
+}
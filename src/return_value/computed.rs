@@ -0,0 +1,48 @@
+use super::ReturnValueGenerator;
+use crate::matcher::ArgumentsMatcher;
+use std::fmt::{self, Debug, Display};
+
+/// Computes the return value from the arguments of a call using a closure.
+pub(crate) struct Computed<F>(pub(crate) F);
+
+impl<A, R, F> ReturnValueGenerator<A, R> for Computed<F>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    F: for<'args> Fn(<A as ArgumentsMatcher<'args>>::Arguments) -> R,
+{
+    fn generate_return_value(&self, input: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        (self.0)(input)
+    }
+}
+
+impl<F> Display for Computed<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<closure>")
+    }
+}
+
+impl<F> Debug for Computed<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Computed").field(&"<closure>").finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+
+    #[test]
+    fn returns_value_computed_by_closure() {
+        let computed = Computed(|arguments: ArgumentsMock| arguments.to_string());
+
+        assert_eq!(
+            String::from("mock"),
+            ReturnValueGenerator::<ArgumentsMatcherMock, String>::generate_return_value(
+                &computed,
+                ArgumentsMock
+            )
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use super::ReturnValueGenerator;
+use crate::fmt::MaybeDebug;
+use crate::matcher::ArgumentsMatcher;
+use std::collections::VecDeque;
+use std::fmt::{self, Debug, Display};
+use std::sync::{Mutex, PoisonError};
+
+/// Returns each of the values once, in the order in which they were given.
+pub(crate) struct InOrder<T> {
+    values: Mutex<VecDeque<T>>,
+    number_of_values: u64,
+}
+
+impl<T> InOrder<T> {
+    pub(crate) fn new(values: Vec<T>) -> Self {
+        Self {
+            number_of_values: values.len() as u64,
+            values: Mutex::new(values.into()),
+        }
+    }
+}
+
+impl<A, R> ReturnValueGenerator<A, R> for InOrder<R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn generate_return_value(&self, _: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        self.values
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("All values were already returned")
+    }
+
+    fn max_number_of_return_values(&self) -> Option<u64> {
+        Some(self.number_of_values)
+    }
+}
+
+impl<R> Display for InOrder<R>
+where
+    R: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        MaybeDebug::fmt(
+            &*self.values.lock().unwrap_or_else(PoisonError::into_inner),
+            f,
+        )
+    }
+}
+
+impl<R> Debug for InOrder<R>
+where
+    R: MaybeDebug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        MaybeDebug::fmt(
+            &*self.values.lock().unwrap_or_else(PoisonError::into_inner),
+            f,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arguments::ArgumentsMock;
+    use crate::matcher::ArgumentsMatcherMock;
+
+    #[test]
+    fn returns_values_in_order() {
+        let return_value = InOrder::new(vec![1, 2, 3]);
+
+        for expected_value in 1..=3 {
+            assert_eq!(
+                expected_value,
+                ReturnValueGenerator::<ArgumentsMatcherMock, i32>::generate_return_value(
+                    &return_value,
+                    ArgumentsMock
+                )
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "All values were already returned")]
+    fn panics_when_all_values_were_returned() {
+        let return_value = InOrder::new(vec![1]);
+
+        ReturnValueGenerator::<ArgumentsMatcherMock, i32>::generate_return_value(
+            &return_value,
+            ArgumentsMock,
+        );
+        ReturnValueGenerator::<ArgumentsMatcherMock, i32>::generate_return_value(
+            &return_value,
+            ArgumentsMock,
+        );
+    }
+
+    #[test]
+    fn max_number_of_return_values_is_number_of_values() {
+        let return_value = InOrder::new(vec![1, 2]);
+
+        assert_eq!(
+            Some(2),
+            ReturnValueGenerator::<ArgumentsMatcherMock, i32>::max_number_of_return_values(
+                &return_value
+            )
+        );
+    }
+}
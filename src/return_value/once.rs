@@ -1,14 +1,14 @@
 use super::ReturnValueGenerator;
 use crate::fmt::MaybeDebug;
 use crate::matcher::ArgumentsMatcher;
-use std::cell::RefCell;
 use std::fmt::{self, Debug, Display};
+use std::sync::{Mutex, PoisonError};
 
-pub(crate) struct Once<T>(RefCell<Option<T>>);
+pub(crate) struct Once<T>(Mutex<Option<T>>);
 
 impl<T> Once<T> {
     pub(crate) fn new(value: T) -> Self {
-        Self(RefCell::new(Some(value)))
+        Self(Mutex::new(Some(value)))
     }
 }
 
@@ -18,7 +18,8 @@ where
 {
     fn generate_return_value(&self, _: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
         self.0
-            .borrow_mut()
+            .lock()
+            .unwrap()
             .take()
             .expect("This value was already returned")
     }
@@ -33,7 +34,7 @@ where
     R: MaybeDebug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        MaybeDebug::fmt(&self.0, f)
+        MaybeDebug::fmt(&*self.0.lock().unwrap_or_else(PoisonError::into_inner), f)
     }
 }
 
@@ -42,7 +43,7 @@ where
     R: MaybeDebug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        MaybeDebug::fmt(&self.0, f)
+        MaybeDebug::fmt(&*self.0.lock().unwrap_or_else(PoisonError::into_inner), f)
     }
 }
 
@@ -0,0 +1,263 @@
+use crate::expected_calls::ExpectedCalls;
+use nameof::name_of;
+use std::fmt::{self, Debug, Display};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Defines an order for expected calls across methods and mocks.
+///
+/// Expected calls join a sequence using [`MethodCallBuilder::in_sequence`].
+/// The calls then have to be made in the same order in which they joined the sequence.
+/// A sequence can be cloned cheaply, all clones refer to the same sequence.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, Sequence};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait File {
+///     fn open(&self);
+///
+///     fn close(&self);
+/// }
+///
+/// let sequence = Sequence::new();
+/// let mut file = FileMock::new();
+///
+/// file.expect_open().in_sequence(&sequence).returns(());
+/// file.expect_close().in_sequence(&sequence).returns(());
+///
+/// file.open();
+/// file.close();
+/// ```
+///
+/// [`MethodCallBuilder::in_sequence`]: crate::MethodCallBuilder::in_sequence
+#[derive(Clone, Default)]
+pub struct Sequence {
+    state: Arc<Mutex<SequenceState>>,
+}
+
+#[derive(Default)]
+struct SequenceState {
+    steps: Vec<SequenceStepState>,
+    current_step: usize,
+}
+
+struct SequenceStepState {
+    description: String,
+    expected_calls: ExpectedCalls,
+    number_of_calls: u64,
+}
+
+impl Sequence {
+    /// Creates a new sequence without any steps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn add_step(
+        &self,
+        description: String,
+        expected_calls: ExpectedCalls,
+    ) -> SequenceStep {
+        let mut state = self.state();
+
+        state.steps.push(SequenceStepState {
+            description,
+            expected_calls,
+            number_of_calls: 0,
+        });
+
+        SequenceStep {
+            sequence: self.clone(),
+            index: state.steps.len() - 1,
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, SequenceState> {
+        // A panic while the lock is held can't leave the state inconsistent,
+        // so the state of a poisoned lock is used nonetheless.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Debug for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state();
+
+        f.debug_struct(name_of!(type Sequence))
+            .field(
+                "steps",
+                &state
+                    .steps
+                    .iter()
+                    .map(|step| &step.description)
+                    .collect::<Vec<_>>(),
+            )
+            .field(name_of!(current_step in SequenceState), &state.current_step)
+            .finish()
+    }
+}
+
+impl SequenceState {
+    fn next_required_step(&self) -> Option<&SequenceStepState> {
+        self.steps[self.current_step..]
+            .iter()
+            .find(|step| !step.is_satisfied())
+    }
+}
+
+impl SequenceStepState {
+    fn is_satisfied(&self) -> bool {
+        self.number_of_calls >= self.expected_calls.min_value()
+    }
+}
+
+/// An expected call's position in a [`Sequence`].
+#[derive(Clone, Debug)]
+pub(crate) struct SequenceStep {
+    sequence: Sequence,
+    index: usize,
+}
+
+impl SequenceStep {
+    pub(crate) fn set_expected_calls(&self, expected_calls: ExpectedCalls) {
+        self.sequence.state().steps[self.index].expected_calls = expected_calls;
+    }
+
+    /// Removes the requirement for this step to be called,
+    /// so that the later steps of the sequence don't wait for it.
+    pub(crate) fn remove(&self) {
+        self.set_expected_calls((..).into());
+    }
+
+    /// Advances the sequence to this step.
+    /// Fails if a previous step has not been called often enough
+    /// or if the sequence has already moved past this step.
+    pub(crate) fn call(&self) -> Result<(), SequenceError> {
+        let mut state = self.sequence.state();
+
+        let previous_steps_are_satisfied = state.current_step <= self.index
+            && state.steps[state.current_step..self.index]
+                .iter()
+                .all(SequenceStepState::is_satisfied);
+
+        if !previous_steps_are_satisfied {
+            let current_step = &state.steps[state.current_step];
+            let expected_step = state.next_required_step().unwrap_or(current_step);
+
+            return Err(SequenceError {
+                expected_step: expected_step.description.clone(),
+            });
+        }
+
+        state.current_step = self.index;
+        state.steps[self.index].number_of_calls += 1;
+
+        Ok(())
+    }
+}
+
+/// A call was made out of the order defined by a [`Sequence`].
+#[derive(Debug)]
+pub(crate) struct SequenceError {
+    expected_step: String,
+}
+
+impl Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "The next expected call in the sequence was:\n{}",
+            self.expected_step
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn steps_can_be_called_in_order() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first"), 1.into());
+        let second = sequence.add_step(String::from("second"), 1.into());
+
+        assert!(first.call().is_ok());
+        assert!(second.call().is_ok());
+    }
+
+    #[test]
+    fn calling_step_before_previous_step_is_satisfied_fails() {
+        let sequence = Sequence::new();
+        let _first = sequence.add_step(String::from("first"), 1.into());
+        let second = sequence.add_step(String::from("second"), 1.into());
+
+        let error = second.call().unwrap_err();
+
+        assert_eq!("first", error.expected_step);
+    }
+
+    #[test]
+    fn calling_previous_step_after_sequence_moved_on_fails() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first"), (1..).into());
+        let second = sequence.add_step(String::from("second"), 1.into());
+
+        assert!(first.call().is_ok());
+        assert!(second.call().is_ok());
+
+        let error = first.call().unwrap_err();
+
+        assert_eq!("second", error.expected_step);
+    }
+
+    #[test]
+    fn steps_without_minimum_number_of_calls_can_be_skipped() {
+        let sequence = Sequence::new();
+        let _first = sequence.add_step(String::from("first"), (..).into());
+        let second = sequence.add_step(String::from("second"), 1.into());
+
+        assert!(second.call().is_ok());
+    }
+
+    #[test]
+    fn step_can_be_called_repeatedly() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first"), 2.into());
+        let second = sequence.add_step(String::from("second"), 1.into());
+
+        assert!(first.call().is_ok());
+        assert!(second.call().is_err());
+        assert!(first.call().is_ok());
+        assert!(second.call().is_ok());
+    }
+
+    #[test]
+    fn expected_calls_of_step_can_be_changed() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first"), 1.into());
+        let second = sequence.add_step(String::from("second"), 1.into());
+
+        first.set_expected_calls((..).into());
+
+        assert!(second.call().is_ok());
+    }
+
+    #[test]
+    fn removed_steps_can_be_skipped() {
+        let sequence = Sequence::new();
+        let first = sequence.add_step(String::from("first"), 2.into());
+        let second = sequence.add_step(String::from("second"), 1.into());
+
+        assert!(first.call().is_ok());
+        first.remove();
+
+        assert!(second.call().is_ok());
+    }
+}
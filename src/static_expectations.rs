@@ -0,0 +1,218 @@
+use crate::matcher::ArgumentsMatcher;
+use crate::method::{Method, FOOTER};
+use crate::method_call::{MethodCall, MethodCallBuilder};
+use crate::thread_safety::ThreadSafety;
+use nameof::name_of;
+use std::any::{Any, TypeId};
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+thread_local! {
+    /// The expectations of associated functions that are currently active on this thread,
+    /// keyed by the [`TypeId`] of the struct holding them.
+    static REGISTRY: RefCell<HashMap<TypeId, Rc<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Holds the expectations of a mock's associated functions (i.e. functions without a `self` receiver).
+///
+/// Associated functions can't access a mock instance, so their expectations are
+/// registered in a thread-local registry for as long as this struct is alive.
+/// This also applies to thread-safe mocks: other threads don't see the expectations.
+#[allow(missing_docs)]
+pub struct StaticExpectations<S>
+where
+    S: 'static,
+{
+    name: &'static str,
+    statics: Rc<RefCell<S>>,
+}
+
+impl<S> Debug for StaticExpectations<S>
+where
+    S: Debug + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type StaticExpectations<S>))
+            .field(name_of!(name in Self), &self.name)
+            .field(name_of!(statics in Self), &self.statics)
+            .finish()
+    }
+}
+
+#[allow(missing_docs)]
+impl<S> StaticExpectations<S>
+where
+    S: 'static,
+{
+    pub fn new(name: &'static str, statics: S) -> Self {
+        let statics = Rc::new(RefCell::new(statics));
+
+        REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+
+            if registry.contains_key(&TypeId::of::<S>()) {
+                panic!(
+                    "\n\nThe expectations for the associated functions of {} are already active \
+                     on this thread.\nDrop the existing guard before creating a new one.{}\n",
+                    name, FOOTER
+                );
+            }
+
+            registry.insert(TypeId::of::<S>(), statics.clone() as Rc<dyn Any>);
+        });
+
+        Self { name, statics }
+    }
+
+    /// Looks up the expectations that are active on this thread.
+    /// `call_name` is only used for the error message.
+    pub fn current(call_name: &str) -> Rc<RefCell<S>> {
+        let statics = REGISTRY.with(|registry| registry.borrow().get(&TypeId::of::<S>()).cloned());
+
+        match statics.and_then(|statics| statics.downcast().ok()) {
+            Some(statics) => statics,
+            None => panic!(
+                "\n\nThe call to {} was not expected.\nNo expectations for associated functions \
+                 are active on this thread.{}\n",
+                call_name, FOOTER
+            ),
+        }
+    }
+
+    /// The expected call is added once the returned builder is dropped,
+    /// so that the associated functions can be called while the builder is alive.
+    pub fn add_expected_call<A, R, T, F>(
+        &self,
+        method: F,
+        matcher: A,
+    ) -> MethodCallBuilder<'static, '_, A, R, T>
+    where
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        T: ThreadSafety<'static, A, R> + 'static,
+        F: FnOnce(&mut S) -> &mut Method<'static, A, R, T> + 'static,
+    {
+        MethodCallBuilder::new(PendingCall {
+            statics: self.statics.clone(),
+            method: Some(method),
+            call: Some(MethodCall::new(matcher)),
+        })
+    }
+
+    pub fn statics(&self) -> Ref<'_, S> {
+        self.statics.borrow()
+    }
+
+    pub fn statics_mut(&self) -> RefMut<'_, S> {
+        self.statics.borrow_mut()
+    }
+}
+
+impl<S> Drop for StaticExpectations<S>
+where
+    S: 'static,
+{
+    fn drop(&mut self) {
+        // The registry is already gone if the thread is shutting down.
+        let _ = REGISTRY.try_with(|registry| registry.borrow_mut().remove(&TypeId::of::<S>()));
+    }
+}
+
+/// An expected call that is added to the expectations when it is dropped.
+struct PendingCall<S, F, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'static, A, R>,
+    F: FnOnce(&mut S) -> &mut Method<'static, A, R, T>,
+{
+    statics: Rc<RefCell<S>>,
+    method: Option<F>,
+    call: Option<MethodCall<'static, A, R, T>>,
+}
+
+impl<S, F, A, R, T> Deref for PendingCall<S, F, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'static, A, R>,
+    F: FnOnce(&mut S) -> &mut Method<'static, A, R, T>,
+{
+    type Target = MethodCall<'static, A, R, T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.call.as_ref().expect("The call is only taken on drop")
+    }
+}
+
+impl<S, F, A, R, T> DerefMut for PendingCall<S, F, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'static, A, R>,
+    F: FnOnce(&mut S) -> &mut Method<'static, A, R, T>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.call.as_mut().expect("The call is only taken on drop")
+    }
+}
+
+impl<S, F, A, R, T> Drop for PendingCall<S, F, A, R, T>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+    T: ThreadSafety<'static, A, R>,
+    F: FnOnce(&mut S) -> &mut Method<'static, A, R, T>,
+{
+    fn drop(&mut self) {
+        if let (Some(method), Some(call)) = (self.method.take(), self.call.take()) {
+            method(&mut self.statics.borrow_mut()).push_configured_call(call);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Statics {
+        value: u32,
+    }
+
+    #[test]
+    fn current_returns_registered_expectations() {
+        let expectations = StaticExpectations::new("Mock", Statics::default());
+        expectations.statics_mut().value = 42;
+
+        assert_eq!(
+            42,
+            StaticExpectations::<Statics>::current("Mock::f")
+                .borrow()
+                .value
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The call to Mock::f was not expected.")]
+    fn current_panics_without_active_expectations() {
+        StaticExpectations::<Statics>::current("Mock::f");
+    }
+
+    #[test]
+    #[should_panic(expected = "No expectations for associated functions are active")]
+    fn expectations_are_unregistered_on_drop() {
+        drop(StaticExpectations::new("Mock", Statics::default()));
+
+        StaticExpectations::<Statics>::current("Mock::f");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The expectations for the associated functions of Mock are already active on \
+                    this thread."
+    )]
+    fn new_panics_if_expectations_are_already_active() {
+        let _expectations = StaticExpectations::new("Mock", Statics::default());
+        let _other_expectations = StaticExpectations::new("Mock", Statics::default());
+    }
+}
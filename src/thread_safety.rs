@@ -0,0 +1,105 @@
+//! Markers that specify whether the expectations of a mock can be shared between threads.
+
+use crate::effect::Effect;
+use crate::matcher::ArgumentsMatcher;
+use crate::return_value::ReturnValueGenerator;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// The expectations are only used on the thread that created them.
+/// This is the default for mocks.
+#[derive(Debug)]
+pub enum SingleThreaded {}
+
+/// The expectations can be shared between threads.
+/// Used for mocks generated with `#[mockable(sync)]`.
+#[derive(Debug)]
+pub enum ThreadSafe {}
+
+/// Implemented by the thread safety markers to choose how the values of an expected call are stored.
+///
+/// [`SingleThreaded`] stores them in an [`Rc`]. [`ThreadSafe`] stores them in an [`Arc`]
+/// of trait objects that are [`Send`] and [`Sync`], so that the compiler can derive
+/// that the expected calls of a thread safe mock are [`Send`] and [`Sync`].
+pub trait ThreadSafety<'mock, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    type Matcher: Deref<Target = A> + Clone;
+    type ReturnValueGenerator: Clone;
+    type Effect: Clone;
+
+    fn store_matcher(matcher: A) -> Self::Matcher;
+
+    /// Stores a generator that can be stored regardless of the thread safety.
+    fn store_return_value_generator<G>(generator: G) -> Self::ReturnValueGenerator
+    where
+        G: ReturnValueGenerator<A, R> + Send + Sync + 'mock;
+
+    fn return_value_generator(
+        generator: &Self::ReturnValueGenerator,
+    ) -> &(dyn ReturnValueGenerator<A, R> + 'mock);
+
+    fn effect(effect: &Self::Effect) -> &(dyn Effect<A> + 'mock);
+}
+
+impl<'mock, A, R> ThreadSafety<'mock, A, R> for SingleThreaded
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    type Matcher = Rc<A>;
+    type ReturnValueGenerator = Rc<dyn ReturnValueGenerator<A, R> + 'mock>;
+    type Effect = Rc<dyn Effect<A> + 'mock>;
+
+    fn store_matcher(matcher: A) -> Self::Matcher {
+        Rc::new(matcher)
+    }
+
+    fn store_return_value_generator<G>(generator: G) -> Self::ReturnValueGenerator
+    where
+        G: ReturnValueGenerator<A, R> + Send + Sync + 'mock,
+    {
+        Rc::new(generator)
+    }
+
+    fn return_value_generator(
+        generator: &Self::ReturnValueGenerator,
+    ) -> &(dyn ReturnValueGenerator<A, R> + 'mock) {
+        &**generator
+    }
+
+    fn effect(effect: &Self::Effect) -> &(dyn Effect<A> + 'mock) {
+        &**effect
+    }
+}
+
+impl<'mock, A, R> ThreadSafety<'mock, A, R> for ThreadSafe
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    type Matcher = Arc<A>;
+    type ReturnValueGenerator = Arc<dyn ReturnValueGenerator<A, R> + Send + Sync + 'mock>;
+    type Effect = Arc<dyn Effect<A> + Send + Sync + 'mock>;
+
+    fn store_matcher(matcher: A) -> Self::Matcher {
+        Arc::new(matcher)
+    }
+
+    fn store_return_value_generator<G>(generator: G) -> Self::ReturnValueGenerator
+    where
+        G: ReturnValueGenerator<A, R> + Send + Sync + 'mock,
+    {
+        Arc::new(generator)
+    }
+
+    fn return_value_generator(
+        generator: &Self::ReturnValueGenerator,
+    ) -> &(dyn ReturnValueGenerator<A, R> + 'mock) {
+        &**generator
+    }
+
+    fn effect(effect: &Self::Effect) -> &(dyn Effect<A> + 'mock) {
+        &**effect
+    }
+}
@@ -0,0 +1,180 @@
+use crate::expected_calls::ExpectedCalls;
+use crate::fmt::DisplayTimes;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::vec;
+
+/// Lists the expected calls of a mock that were not called the expected number of times.
+/// This is returned by [`verify`](crate::verify).
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+///
+/// greeter
+///     .expect_greet(|arg| arg.partial_eq("Jane"))
+///     .times(2)
+///     .returns(String::from("Hello Jane"));
+///
+/// greeter.greet("Jane");
+///
+/// let report = mockiato::verify(greeter).unwrap_err();
+/// let unsatisfied_expectation = &report.unsatisfied_expectations()[0];
+///
+/// assert_eq!("GreeterMock::greet", unsatisfied_expectation.method_name());
+/// assert_eq!(1, unsatisfied_expectation.number_of_calls());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockVerificationReport {
+    unsatisfied_expectations: Vec<UnsatisfiedExpectation>,
+}
+
+impl MockVerificationReport {
+    /// The expected calls that were not called the expected number of times.
+    pub fn unsatisfied_expectations(&self) -> &[UnsatisfiedExpectation] {
+        &self.unsatisfied_expectations
+    }
+
+    /// Returns `true` if the report doesn't contain any unsatisfied expectations.
+    pub fn is_empty(&self) -> bool {
+        self.unsatisfied_expectations.is_empty()
+    }
+}
+
+impl Extend<UnsatisfiedExpectation> for MockVerificationReport {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = UnsatisfiedExpectation>,
+    {
+        self.unsatisfied_expectations.extend(iter)
+    }
+}
+
+impl IntoIterator for MockVerificationReport {
+    type Item = UnsatisfiedExpectation;
+    type IntoIter = vec::IntoIter<UnsatisfiedExpectation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.unsatisfied_expectations.into_iter()
+    }
+}
+
+impl Display for MockVerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "The following expected calls were not satisfied:")?;
+
+        for unsatisfied_expectation in &self.unsatisfied_expectations {
+            writeln!(f, "{}", unsatisfied_expectation)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for MockVerificationReport {}
+
+/// An expected call that was not called the expected number of times.
+#[derive(Clone, Debug)]
+pub struct UnsatisfiedExpectation {
+    method_name: String,
+    call: String,
+    expected_calls: ExpectedCalls,
+    number_of_calls: u64,
+}
+
+impl UnsatisfiedExpectation {
+    pub(crate) fn new(
+        method_name: String,
+        call: String,
+        expected_calls: ExpectedCalls,
+        number_of_calls: u64,
+    ) -> Self {
+        Self {
+            method_name,
+            call,
+            expected_calls,
+            number_of_calls,
+        }
+    }
+
+    /// The name of the mocked method, e.g. `GreeterMock::greet`.
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    /// The expected call including its argument matchers, e.g. `greet("Jane")`.
+    pub fn call(&self) -> &str {
+        &self.call
+    }
+
+    /// How often the call was expected to be called.
+    pub fn expected_calls(&self) -> &ExpectedCalls {
+        &self.expected_calls
+    }
+
+    /// How often the call was actually called.
+    pub fn number_of_calls(&self) -> u64 {
+        self.number_of_calls
+    }
+}
+
+impl Display for UnsatisfiedExpectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} was expected to be called {}, but was called {}",
+            self.method_name,
+            self.call,
+            self.expected_calls,
+            DisplayTimes(self.number_of_calls)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn report_lists_all_unsatisfied_expectations() {
+        let mut report = MockVerificationReport::default();
+
+        report.extend(vec![
+            UnsatisfiedExpectation::new(
+                String::from("GreeterMock::greet"),
+                String::from("greet(\"Jane\")"),
+                1.into(),
+                0,
+            ),
+            UnsatisfiedExpectation::new(
+                String::from("GreeterMock::wave"),
+                String::from("wave()"),
+                (2..).into(),
+                1,
+            ),
+        ]);
+
+        assert_eq!(
+            "The following expected calls were not satisfied:\nGreeterMock::greet: \
+             greet(\"Jane\") was expected to be called exactly 1 time, but was called 0 \
+             times\nGreeterMock::wave: wave() was expected to be called at least 2 times, but was \
+             called 1 time\n",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn default_report_is_empty() {
+        assert!(MockVerificationReport::default().is_empty());
+    }
+}
@@ -0,0 +1,36 @@
+use mockiato::mockable;
+
+#[mockable(constants(NAME = "greeter", MAX_LENGTH = 10))]
+trait Greeter {
+    const NAME: &'static str;
+
+    const MAX_LENGTH: usize;
+
+    const ENABLED: bool = true;
+
+    fn greet(&self, name: &str) -> String;
+}
+
+#[mockable(constants(ENABLED = false))]
+trait Feature {
+    const ENABLED: bool = true;
+
+    const PRIORITY: u8 = 1;
+}
+
+#[test]
+fn associated_consts_have_specified_values() {
+    assert_eq!("greeter", <GreeterMock as Greeter>::NAME);
+    assert_eq!(10, <GreeterMock as Greeter>::MAX_LENGTH);
+}
+
+#[test]
+fn associated_consts_without_specified_value_use_default() {
+    assert!(<GreeterMock as Greeter>::ENABLED);
+    assert_eq!(1, <FeatureMock as Feature>::PRIORITY);
+}
+
+#[test]
+fn specified_value_overrides_default() {
+    assert!(!<FeatureMock as Feature>::ENABLED);
+}
@@ -0,0 +1,156 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[mockable]
+trait Connection {
+    fn connect(url: &str) -> Self;
+
+    fn default_port() -> u16;
+
+    fn send(&self, message: &str) -> bool;
+}
+
+#[mockable]
+trait Server {
+    fn default_port() -> u16;
+
+    fn handle(&self, request: &str) -> bool;
+}
+
+#[mockable]
+trait Parser<T>
+where
+    T: Debug,
+{
+    fn parse(input: &str) -> Option<T>;
+}
+
+#[test]
+fn associated_function_can_be_mocked() {
+    let mut statics = ConnectionMock::static_expectations();
+
+    statics.expect_default_port().returns(8080);
+
+    assert_eq!(8080, ConnectionMock::default_port());
+}
+
+#[test]
+fn associated_function_can_return_mock() {
+    let mut statics = ConnectionMock::static_expectations();
+
+    let mut connection = ConnectionMock::new();
+    connection
+        .expect_send(|arg| arg.partial_eq("ping"))
+        .returns(true);
+
+    statics
+        .expect_connect(|arg| arg.partial_eq("localhost"))
+        .returns_once(connection);
+
+    let connection = ConnectionMock::connect("localhost");
+    assert!(connection.send("ping"));
+}
+
+#[test]
+fn associated_function_can_be_called_through_trait() {
+    fn connect<C: Connection>() -> u16 {
+        C::default_port()
+    }
+
+    let mut statics = ConnectionMock::static_expectations();
+
+    statics.expect_default_port().times(2).returns(443);
+
+    assert_eq!(443, connect::<ConnectionMock>());
+    assert_eq!(443, connect::<ConnectionMock>());
+}
+
+#[test]
+fn associated_function_can_be_called_while_expectation_is_configured() {
+    let mut statics = ConnectionMock::static_expectations();
+
+    let mut builder = statics.expect_default_port();
+    builder.returns(22);
+    drop(builder);
+
+    let mut builder = statics.expect_connect(|arg| arg.any());
+    assert_eq!(22, ConnectionMock::default_port());
+    builder.returns_once(ConnectionMock::new());
+    drop(builder);
+
+    ConnectionMock::connect("localhost");
+}
+
+#[test]
+fn calls_in_order_are_supported_for_associated_functions() {
+    let mut statics = ParserMock::<u32>::static_expectations();
+
+    statics.expect_parse_calls_in_order();
+    statics
+        .expect_parse(|arg| arg.partial_eq("1"))
+        .returns(Some(1));
+    statics.expect_parse(|arg| arg.any()).returns(None);
+
+    assert_eq!(Some(1), ParserMock::<u32>::parse("1"));
+    assert_eq!(None, ParserMock::<u32>::parse("one"));
+}
+
+#[test]
+fn mock_without_associated_function_calls_can_be_dropped() {
+    let _statics = ConnectionMock::static_expectations();
+    let _connection = ConnectionMock::new();
+}
+
+#[test]
+#[should_panic(expected = "No expectations for associated functions are active on this thread.")]
+fn panics_when_no_expectations_are_active() {
+    ConnectionMock::default_port();
+}
+
+#[test]
+#[should_panic(
+    expected = "The expected calls for ConnectionMock::default_port were not satisified."
+)]
+fn panics_when_expected_associated_function_was_not_called() {
+    let mut statics = ConnectionMock::static_expectations();
+
+    statics.expect_default_port().returns(8080);
+}
+
+#[test]
+#[should_panic(
+    expected = "The expectations for the associated functions of ConnectionMock are already \
+                active on this thread."
+)]
+fn panics_when_guard_is_created_twice() {
+    let _statics = ConnectionMock::static_expectations();
+    let _other_statics = ConnectionMock::static_expectations();
+}
+
+#[test]
+fn guard_can_be_recreated_after_being_dropped() {
+    {
+        let mut statics = ConnectionMock::static_expectations();
+        statics.expect_default_port().returns(80);
+        assert_eq!(80, ConnectionMock::default_port());
+    }
+
+    let mut statics = ConnectionMock::static_expectations();
+    statics.expect_default_port().returns(443);
+    assert_eq!(443, ConnectionMock::default_port());
+}
+
+#[test]
+fn expectations_of_methods_can_borrow_values_if_trait_has_associated_functions() {
+    let request = String::from("ping");
+    let mut statics = ServerMock::static_expectations();
+    let mut server = ServerMock::new();
+
+    statics.expect_default_port().returns(8080);
+    server
+        .expect_handle(|arg| arg.partial_eq(&request[..]))
+        .returns(true);
+
+    assert_eq!(8080, ServerMock::default_port());
+    assert!(server.handle("ping"));
+}
@@ -0,0 +1,77 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Item {
+    id: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct NotFound;
+
+#[mockable]
+trait Repository {
+    type Error: Debug;
+
+    type Key;
+
+    fn load(&self, key: Self::Key) -> Result<Item, Self::Error>;
+
+    fn store(&self, item: Item) -> Result<(), <Self as Repository>::Error>;
+}
+
+#[mockable]
+trait Converter<T> {
+    type Output;
+
+    fn convert(&self, value: T) -> Self::Output;
+}
+
+#[test]
+fn associated_types_can_be_specified_as_generic_parameters() {
+    let mut repository: RepositoryMock<NotFound, u32> = RepositoryMock::new();
+
+    repository
+        .expect_load(|arg| arg.partial_eq(1))
+        .returns(Ok(Item { id: 1 }));
+    repository
+        .expect_load(|arg| arg.partial_eq(2))
+        .returns(Err(NotFound));
+
+    assert_eq!(Ok(Item { id: 1 }), repository.load(1));
+    assert_eq!(Err(NotFound), repository.load(2));
+}
+
+#[test]
+fn associated_types_can_be_inferred() {
+    let mut repository: RepositoryMock<_, u32> = RepositoryMock::new();
+
+    repository
+        .expect_store(|arg| arg.partial_eq(Item { id: 3 }))
+        .returns(Err(NotFound));
+
+    assert_eq!(Err(NotFound), repository.store(Item { id: 3 }));
+}
+
+#[test]
+fn associated_types_work_with_generic_traits() {
+    let mut converter: ConverterMock<u8, String> = ConverterMock::new();
+
+    converter
+        .expect_convert(|arg| arg.partial_eq(1))
+        .returns(String::from("one"));
+
+    assert_eq!("one", converter.convert(1));
+}
+
+#[test]
+fn mock_can_be_used_as_trait_object() {
+    let mut repository: RepositoryMock<NotFound, u32> = RepositoryMock::new();
+
+    repository
+        .expect_store(|arg| arg.partial_eq(Item { id: 3 }))
+        .returns(Ok(()));
+
+    let repository: &dyn Repository<Error = NotFound, Key = u32> = &repository;
+    assert_eq!(Ok(()), repository.store(Item { id: 3 }));
+}
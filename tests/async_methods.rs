@@ -0,0 +1,108 @@
+#![cfg(rustc_supports_async_fn_in_trait)]
+
+use mockiato::mockable;
+use std::future::Future;
+use std::pin::Pin;
+use std::ptr;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+#[mockable]
+trait Greeter {
+    async fn greet(&self, name: &str) -> String;
+
+    async fn notify(&self);
+
+    async fn greet_twice(&self, name: &str) -> String {
+        format!("{} {}", self.greet(name).await, self.greet(name).await)
+    }
+}
+
+#[test]
+fn async_methods_return_configured_value() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    assert_eq!("Hello Jane", block_on(greeter.greet("Jane")));
+}
+
+#[test]
+fn async_methods_work_with_returns_once() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_notify().returns_once(());
+
+    block_on(greeter.notify());
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn panics_when_future_of_unexpected_call_is_polled() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(0..)
+        .returns(String::from("Hello Jane"));
+
+    block_on(greeter.greet("Doe"));
+}
+
+#[test]
+fn unpolled_futures_do_not_count_as_calls() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(1)
+        .returns(String::from("Hello"));
+
+    let _unpolled = greeter.greet("Jane");
+
+    assert_eq!("Hello", block_on(greeter.greet("Doe")));
+}
+
+#[test]
+fn async_methods_can_call_default_impl() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(2)
+        .returns(String::from("Hello"));
+    greeter
+        .expect_greet_twice(|arg| arg.partial_eq("Jane"))
+        .calls_default();
+
+    assert_eq!("Hello Hello", block_on(greeter.greet_twice("Jane")));
+}
+
+/// Drives a future to completion. The futures returned by mocks never
+/// return [`Poll::Pending`], so no real waker is needed.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut future = Box::pin(future);
+    let waker = noop_waker();
+    let mut context = Context::from_waker(&waker);
+
+    match Pin::as_mut(&mut future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("Future returned by mock should be ready immediately"),
+    }
+}
+
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(ptr::null(), &VTABLE)
+    }
+
+    fn noop(_: *const ()) {}
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+}
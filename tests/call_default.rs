@@ -0,0 +1,131 @@
+use mockiato::mockable;
+use std::fmt::Display;
+
+#[mockable]
+trait Greeter {
+    fn name(&self) -> String;
+
+    fn greet(&self, greeting: &str) -> String {
+        format!("{}, {}!", greeting, self.name())
+    }
+
+    fn greet_all(&self, (first, second): (&str, &str)) -> String {
+        format!("{} and {}", self.greet(first), self.greet(second))
+    }
+
+    fn default_greeting() -> String {
+        String::from("Hello")
+    }
+}
+
+#[mockable]
+trait Counter {
+    fn increment(&mut self, amount: u32) -> u32;
+
+    fn increment_twice(&mut self, amount: u32) -> u32 {
+        self.increment(amount);
+        self.increment(amount)
+    }
+}
+
+#[mockable]
+trait Formatter {
+    fn format<T>(&self, value: T) -> String
+    where
+        T: Display + 'static,
+    {
+        format!("<{}>", value)
+    }
+}
+
+#[mockable]
+trait Store {
+    type Item: Clone;
+
+    fn get(&self) -> Self::Item;
+
+    fn get_twice(&self) -> (Self::Item, Self::Item) {
+        (self.get(), self.get())
+    }
+}
+
+#[test]
+fn default_impl_is_called() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_name().returns(String::from("Ferris"));
+    greeter.expect_greet(|arg| arg.any()).calls_default();
+
+    assert_eq!("Hi, Ferris!", greeter.greet("Hi"));
+}
+
+#[test]
+fn default_impl_calls_mocked_methods() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Hi"))
+        .returns(String::from("Hi!"));
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Bye"))
+        .returns(String::from("Bye!"));
+    greeter.expect_greet_all(|arg| arg.any()).calls_default();
+
+    assert_eq!("Hi! and Bye!", greeter.greet_all(("Hi", "Bye")));
+}
+
+#[test]
+fn methods_with_default_impl_can_be_mocked() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .returns(String::from("Hey!"));
+
+    assert_eq!("Hey!", greeter.greet("Hi"));
+}
+
+#[test]
+fn default_impl_can_take_self_mutably() {
+    let mut counter = CounterMock::new();
+
+    counter
+        .expect_increment(|arg| arg.partial_eq(2))
+        .times(2)
+        .returns(4);
+    counter
+        .expect_increment_twice(|arg| arg.any())
+        .calls_default();
+
+    assert_eq!(4, counter.increment_twice(2));
+}
+
+#[test]
+fn default_impl_of_associated_function_is_called() {
+    let mut statics = GreeterMock::static_expectations();
+
+    statics.expect_default_greeting().calls_default();
+
+    assert_eq!("Hello", GreeterMock::default_greeting());
+}
+
+#[test]
+fn default_impl_of_generic_method_is_called() {
+    let mut formatter = FormatterMock::new();
+
+    formatter
+        .expect_format(|arg| arg.partial_eq(42))
+        .calls_default();
+
+    assert_eq!("<42>", formatter.format(42));
+}
+
+#[test]
+fn default_impl_can_use_associated_types() {
+    let mut store = StoreMock::<u8>::new();
+
+    store.expect_get().times(2).returns(7);
+    store.expect_get_twice().calls_default();
+
+    assert_eq!((7, 7), store.get_twice());
+}
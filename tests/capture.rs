@@ -0,0 +1,95 @@
+use mockiato::{mockable, Captured};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Message {
+    text: String,
+}
+
+#[mockable]
+trait MessageSender {
+    fn send(&self, recipient: &str, message: Message);
+}
+
+#[mockable]
+trait Logger {
+    fn log(&self, level: u8, message: &str);
+}
+
+#[test]
+fn owned_arguments_are_captured() {
+    let captured = Captured::new();
+    let mut sender = MessageSenderMock::new();
+
+    sender
+        .expect_send(|arg| arg.any(), |arg| arg.capture(&captured))
+        .times(2)
+        .returns(());
+
+    sender.send(
+        "Jane",
+        Message {
+            text: String::from("Hello"),
+        },
+    );
+    sender.send(
+        "Doe",
+        Message {
+            text: String::from("World"),
+        },
+    );
+
+    assert_eq!(
+        vec![
+            Message {
+                text: String::from("Hello")
+            },
+            Message {
+                text: String::from("World")
+            }
+        ],
+        captured.values()
+    );
+}
+
+#[test]
+fn borrowed_arguments_are_captured_as_owned_values() {
+    let captured = Captured::new();
+    let mut logger = LoggerMock::new();
+
+    logger
+        .expect_log(|arg| arg.partial_eq(1), |arg| arg.capture_owned(&captured))
+        .returns(());
+
+    let message = String::from("Something happened");
+    logger.log(1, &message);
+    drop(message);
+
+    assert_eq!(Some(String::from("Something happened")), captured.last());
+}
+
+#[test]
+fn arguments_are_only_captured_for_expected_call_that_is_called() {
+    let captured_info = Captured::new();
+    let captured_error = Captured::new();
+    let mut logger = LoggerMock::new();
+
+    logger
+        .expect_log(
+            |arg| arg.partial_eq(1),
+            |arg| arg.capture_owned(&captured_info),
+        )
+        .times(..)
+        .returns(());
+    logger
+        .expect_log(
+            |arg| arg.partial_eq(2),
+            |arg| arg.capture_owned(&captured_error),
+        )
+        .times(..)
+        .returns(());
+
+    logger.log(2, "Error");
+
+    assert!(captured_info.is_empty());
+    assert_eq!(vec![String::from("Error")], captured_error.values());
+}
@@ -0,0 +1,126 @@
+use mockiato::{mockable, Sequence};
+use std::fmt::Debug;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn print<T>(&self, value: T)
+    where
+        T: Debug + 'static;
+}
+
+#[mockable]
+trait Reader {
+    fn read(&self) -> u8;
+}
+
+#[mockable(supertrait_mocks(Reader = "ReaderMock"))]
+trait Connection: Reader {
+    fn send(&self, data: u8);
+}
+
+#[test]
+fn checkpoint_allows_configuring_new_expectations() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+    greeter.expect_print(|arg| arg.partial_eq(1_u8)).returns(());
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+    greeter.print(1_u8);
+
+    mockiato::checkpoint(&mut greeter);
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hi Jane"));
+
+    assert_eq!("Hi Jane", greeter.greet("Jane"));
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for GreeterMock::greet were not satisified.")]
+fn checkpoint_panics_if_expected_calls_were_not_made() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .returns(String::from("Hello"));
+
+    mockiato::checkpoint(&mut greeter);
+}
+
+#[test]
+#[should_panic(expected = "The call GreeterMock::greet")]
+fn checkpoint_removes_expected_calls() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(..)
+        .returns(String::from("Hello"));
+
+    mockiato::checkpoint(&mut greeter);
+
+    greeter.greet("Jane");
+}
+
+#[test]
+fn checkpoint_allows_configuring_new_expectations_in_sequence() {
+    let sequence = Sequence::new();
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .in_sequence(&sequence)
+        .returns(String::from("Hello Jane"));
+    greeter
+        .expect_print(|arg| arg.partial_eq(1_u8))
+        .times(..)
+        .in_sequence(&sequence)
+        .returns(());
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+
+    mockiato::checkpoint(&mut greeter);
+
+    greeter
+        .expect_print(|arg| arg.partial_eq(2_u8))
+        .in_sequence(&sequence)
+        .returns(());
+    greeter
+        .expect_greet(|arg| arg.partial_eq("John"))
+        .in_sequence(&sequence)
+        .returns(String::from("Hello John"));
+
+    greeter.print(2_u8);
+    assert_eq!("Hello John", greeter.greet("John"));
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for ReaderMock::read were not satisified.")]
+fn checkpoint_verifies_supertrait_mocks() {
+    let mut connection = ConnectionMock::new();
+
+    connection.reader_mock().expect_read().returns(1);
+
+    mockiato::checkpoint(&mut connection);
+}
+
+#[mockable]
+trait Snapshot {
+    fn checkpoint(&self) -> u32;
+}
+
+#[test]
+fn checkpoint_does_not_collide_with_trait_method_of_the_same_name() {
+    let mut snapshot = SnapshotMock::new();
+
+    snapshot.expect_checkpoint().returns(1);
+    assert_eq!(1, snapshot.checkpoint());
+
+    mockiato::checkpoint(&mut snapshot);
+}
@@ -0,0 +1,93 @@
+use mockiato::{mockable, ArgumentMatcher};
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Pending,
+    Paid,
+}
+
+#[derive(Debug)]
+struct Order {
+    status: Status,
+}
+
+#[derive(Debug)]
+struct StatusMatcher(Status);
+
+impl Display for StatusMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "order with status {:?}", self.0)
+    }
+}
+
+impl ArgumentMatcher<&Order> for StatusMatcher {
+    fn matches_argument(&self, input: &&Order) -> bool {
+        input.status == self.0
+    }
+}
+
+impl ArgumentMatcher<Order> for StatusMatcher {
+    fn matches_argument(&self, input: &Order) -> bool {
+        input.status == self.0
+    }
+}
+
+#[mockable]
+trait OrderProcessor {
+    fn process(&self, order: &Order) -> u32;
+
+    fn archive(&self, order: Order);
+}
+
+#[test]
+fn custom_matcher_matches_reference_arguments() {
+    let mut processor = OrderProcessorMock::new();
+
+    processor
+        .expect_process(|arg| arg.matching(StatusMatcher(Status::Paid)))
+        .returns(1);
+    processor
+        .expect_process(|arg| arg.matching(StatusMatcher(Status::Pending)))
+        .returns(2);
+
+    assert_eq!(
+        1,
+        processor.process(&Order {
+            status: Status::Paid
+        })
+    );
+    assert_eq!(
+        2,
+        processor.process(&Order {
+            status: Status::Pending
+        })
+    );
+}
+
+#[test]
+fn custom_matcher_matches_owned_arguments() {
+    let mut processor = OrderProcessorMock::new();
+
+    processor
+        .expect_archive(|arg| arg.matching(StatusMatcher(Status::Paid)))
+        .returns(());
+
+    processor.archive(Order {
+        status: Status::Paid,
+    });
+}
+
+#[test]
+#[should_panic(expected = "process(order with status Paid)")]
+fn custom_matcher_is_displayed_in_failure_message() {
+    let mut processor = OrderProcessorMock::new();
+
+    processor
+        .expect_process(|arg| arg.matching(StatusMatcher(Status::Paid)))
+        .returns(1);
+
+    processor.process(&Order {
+        status: Status::Pending,
+    });
+}
@@ -0,0 +1,62 @@
+use mockiato::mockable;
+use std::io::{self, Read};
+
+#[cfg(test)]
+#[mockable(remote = "io::Read")]
+trait Read {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+}
+
+#[mockable]
+trait Counter {
+    fn increment(&self, value: &mut u32);
+}
+
+#[test]
+fn effect_writes_to_mutable_reference() {
+    let mut reader = ReadMock::new();
+
+    reader
+        .expect_read(|arg| arg.any())
+        .with_effect(|arguments| arguments.buf[..3].copy_from_slice(b"abc"))
+        .returns_with(|_| Ok(3));
+
+    let mut buf = [0; 8];
+    let bytes_read = reader.read(&mut buf).unwrap();
+
+    assert_eq!(3, bytes_read);
+    assert_eq!(b"abc", &buf[..bytes_read]);
+}
+
+#[test]
+fn mock_can_be_used_as_remote_trait() {
+    let mut reader = ReadMock::new();
+
+    reader
+        .expect_read(|arg| arg.any())
+        .with_effect(|arguments| arguments.buf[..5].copy_from_slice(b"hello"))
+        .returns_with(|_| Ok(5));
+    reader.expect_read(|arg| arg.any()).returns_with(|_| Ok(0));
+    reader.expect_read_calls_in_order();
+
+    let mut content = String::new();
+    reader.read_to_string(&mut content).unwrap();
+
+    assert_eq!("hello", content);
+}
+
+#[test]
+fn effects_are_applied_in_order() {
+    let mut counter = CounterMock::new();
+
+    counter
+        .expect_increment(|arg| arg.any())
+        .with_effect(|arguments| *arguments.value += 1)
+        .with_effect(|arguments| *arguments.value *= 10)
+        .returns(());
+
+    let mut value = 1;
+    counter.increment(&mut value);
+
+    assert_eq!(20, value);
+}
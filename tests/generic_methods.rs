@@ -0,0 +1,118 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[mockable]
+trait Storage {
+    fn store<T: Debug + 'static>(&self, value: T);
+
+    fn load<T>(&self, key: &str) -> T
+    where
+        T: 'static;
+
+    fn convert<T: 'static, U: 'static>(&self, value: T) -> U;
+
+    fn type_name<T: 'static>(&self) -> &str;
+}
+
+#[mockable]
+trait Registry<K>
+where
+    K: Debug,
+{
+    fn register<V: 'static>(&self, key: K, value: V) -> bool;
+}
+
+#[test]
+fn expectations_are_registered_per_instantiation() {
+    let mut storage = StorageMock::new();
+
+    storage
+        .expect_store(|arg| arg.partial_eq(5u32))
+        .times(2)
+        .returns(());
+    storage.expect_store(|arg| arg.partial_eq(true)).returns(());
+
+    storage.store(5u32);
+    storage.store(true);
+    storage.store(5u32);
+}
+
+#[test]
+fn references_in_return_type_are_returned_as_static_references() {
+    let mut storage = StorageMock::new();
+
+    storage.expect_type_name::<u32>().returns("u32");
+
+    assert_eq!("u32", storage.type_name::<u32>());
+}
+
+#[test]
+fn type_parameter_can_be_used_as_return_type() {
+    let mut storage = StorageMock::new();
+
+    storage
+        .expect_load(|arg| arg.partial_eq("answer"))
+        .returns(42u8);
+    storage
+        .expect_load(|arg| arg.partial_eq("greeting"))
+        .returns(String::from("Hello"));
+
+    assert_eq!(42u8, storage.load("answer"));
+    assert_eq!("Hello", storage.load::<String>("greeting"));
+}
+
+#[test]
+fn method_with_multiple_type_parameters_can_be_mocked() {
+    let mut storage = StorageMock::new();
+
+    storage
+        .expect_convert(|arg| arg.partial_eq(1u8))
+        .returns(1u64);
+    storage
+        .expect_convert(|arg| arg.partial_eq(1u8))
+        .returns(String::from("1"));
+
+    assert_eq!(1u64, storage.convert(1u8));
+    assert_eq!("1", storage.convert::<_, String>(1u8));
+}
+
+#[test]
+fn calls_in_order_apply_to_all_instantiations() {
+    let mut storage = StorageMock::new();
+
+    storage.expect_store_calls_in_order();
+    storage.expect_store(|arg| arg.partial_eq(1u32)).returns(());
+    storage.expect_store(|arg| arg.partial_eq(2u32)).returns(());
+
+    storage.store(1u32);
+    storage.store(2u32);
+}
+
+#[test]
+#[should_panic(expected = "No calls to StorageMock::store were expected for these type arguments")]
+fn panics_when_instantiation_was_not_expected() {
+    let mut storage = StorageMock::new();
+
+    storage.expect_store(|arg| arg.partial_eq(5u32)).times(0..);
+
+    storage.store(5u64);
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for StorageMock::store::<u32> were not satisified.")]
+fn panics_when_expected_instantiation_was_not_called() {
+    let mut storage = StorageMock::new();
+
+    storage.expect_store(|arg| arg.partial_eq(5u32));
+}
+
+#[test]
+fn generic_method_on_generic_trait_can_be_mocked() {
+    let mut registry: RegistryMock<&str> = RegistryMock::new();
+
+    registry
+        .expect_register(|arg| arg.partial_eq("foo"), |arg| arg.partial_eq(1u8))
+        .returns(true);
+
+    assert!(registry.register("foo", 1u8));
+}
@@ -0,0 +1,79 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Resizer {
+    fn resize(&self, _: u32, height: u32) -> bool;
+
+    fn area(&self, (width, height): (u32, u32)) -> u32 {
+        width * height
+    }
+
+    fn scale(&self, arg1: u32, _: u32, _arg0: u32) -> u32;
+}
+
+mod remote {
+    #[allow(dead_code)]
+    pub(crate) struct Point {
+        pub(crate) x: i32,
+        pub(crate) y: i32,
+    }
+
+    pub(crate) trait Plotter {
+        fn plot(&self, point: Point, _: bool) -> bool;
+    }
+}
+
+#[mockable(remote = "remote::Plotter")]
+trait Plotter {
+    fn plot(&self, remote::Point { x, y }: remote::Point, _: bool) -> bool;
+}
+
+#[test]
+fn ignored_arguments_are_named_by_position() {
+    let mut resizer = ResizerMock::new();
+
+    resizer
+        .expect_resize(|arg| arg.partial_eq(10u32), |arg| arg.partial_eq(20u32))
+        .returns(true);
+
+    assert!(resizer.resize(10, 20));
+}
+
+#[test]
+fn ignored_arguments_do_not_collide_with_named_arguments() {
+    let mut resizer = ResizerMock::new();
+
+    resizer
+        .expect_scale(
+            |arg| arg.partial_eq(1u32),
+            |arg| arg.partial_eq(2u32),
+            |arg| arg.partial_eq(3u32),
+        )
+        .returns_with(|arguments| arguments.arg1 * 100 + arguments._arg1 * 10 + arguments.arg0);
+
+    assert_eq!(123, resizer.scale(1, 2, 3));
+}
+
+#[test]
+fn tuple_pattern_arguments_can_be_matched() {
+    let mut resizer = ResizerMock::new();
+
+    resizer
+        .expect_area(|arg| arg.partial_eq((2u32, 3u32)))
+        .returns(6);
+
+    assert_eq!(6, resizer.area((2, 3)));
+}
+
+#[test]
+fn struct_pattern_arguments_can_be_matched() {
+    use remote::Plotter as _;
+
+    let mut plotter = PlotterMock::new();
+
+    plotter
+        .expect_plot(|arg| arg.any(), |arg| arg.partial_eq(true))
+        .returns(false);
+
+    assert!(!plotter.plot(remote::Point { x: 1, y: 2 }, true));
+}
@@ -0,0 +1,125 @@
+use mockiato::{mockable, Argument, ArgumentMatcher, Captured};
+
+#[mockable]
+trait Divider {
+    fn divide(&self, dividend: u32, divisor: u32) -> u32;
+
+    fn describe(&self, name: &str) -> String;
+}
+
+fn is(value: u32) -> impl ArgumentMatcher<u32> {
+    Argument::internal_new().satisfies(value.to_string(), move |input: &u32| *input == value)
+}
+
+#[test]
+fn not_matches_values_not_matched_by_inner_matcher() {
+    let mut divider = DividerMock::new();
+
+    divider
+        .expect_divide(|arg| arg.any(), |arg| arg.not(arg.partial_eq(0)))
+        .times(2)
+        .returns(1);
+
+    divider.divide(4, 2);
+    divider.divide(4, 4);
+}
+
+#[test]
+#[should_panic(expected = "was not expected")]
+fn not_does_not_match_values_matched_by_inner_matcher() {
+    let mut divider = DividerMock::new();
+
+    divider
+        .expect_divide(|arg| arg.any(), |arg| arg.not(arg.partial_eq(0)))
+        .times(..)
+        .returns(1);
+
+    divider.divide(4, 0);
+}
+
+#[test]
+fn any_of_matches_alternatives_with_single_expectation() {
+    let mut divider = DividerMock::new();
+
+    divider
+        .expect_divide(
+            |arg| arg.any_of(vec![arg.partial_eq(1), arg.partial_eq(2)]),
+            |arg| arg.any(),
+        )
+        .times(2)
+        .returns(1);
+
+    divider.divide(1, 1);
+    divider.divide(2, 1);
+}
+
+#[test]
+fn all_of_matches_reference_arguments() {
+    let mut divider = DividerMock::new();
+
+    divider
+        .expect_describe(|arg| {
+            arg.all_of(vec![
+                arg.not(arg.partial_eq("foo")),
+                arg.not(arg.partial_eq("bar")),
+            ])
+        })
+        .returns(String::from("baz"));
+
+    assert_eq!("baz", divider.describe("baz"));
+}
+
+#[test]
+fn matchers_of_different_types_can_be_combined_by_boxing() {
+    let mut divider = DividerMock::new();
+
+    divider
+        .expect_divide(
+            |arg| {
+                arg.any_of(vec![
+                    Box::new(arg.partial_eq(1_u32)) as Box<dyn ArgumentMatcher<u32>>,
+                    Box::new(arg.satisfies("is even", |value: &u32| value % 2 == 0)),
+                ])
+            },
+            |arg| arg.any(),
+        )
+        .times(2)
+        .returns(1);
+
+    divider.divide(1, 1);
+    divider.divide(4, 1);
+}
+
+#[test]
+fn all_of_forwards_calls_to_capturing_matchers() {
+    let captured = Captured::new();
+    let mut divider = DividerMock::new();
+
+    divider
+        .expect_divide(
+            |arg| {
+                arg.all_of(vec![
+                    Box::new(arg.not(arg.partial_eq(0_u32))) as Box<dyn ArgumentMatcher<u32>>,
+                    Box::new(arg.capture(&captured)),
+                ])
+            },
+            |arg| arg.any(),
+        )
+        .returns(1);
+
+    divider.divide(3, 1);
+
+    assert_eq!(vec![3], captured.values());
+}
+
+#[test]
+#[should_panic(expected = "divide((1 | 2), *)")]
+fn any_of_is_displayed_in_failure_message() {
+    let mut divider = DividerMock::new();
+
+    divider
+        .expect_divide(|arg| arg.any_of(vec![is(1), is(2)]), |arg| arg.any())
+        .returns(1);
+
+    divider.divide(3, 1);
+}
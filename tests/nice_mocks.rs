@@ -0,0 +1,69 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[mockable(nice)]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn count(&self) -> u32;
+
+    fn print<T>(&self, value: T)
+    where
+        T: Debug + 'static;
+
+    fn wave(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn unexpected_calls_return_default_value() {
+    let greeter = GreeterMock::new();
+
+    assert_eq!("", greeter.greet("Jane"));
+    assert_eq!(0, greeter.count());
+    assert!(!greeter.wave());
+    greeter.print(1_u8);
+}
+
+#[test]
+fn expected_calls_return_configured_value() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    assert_eq!("", greeter.greet("Doe"));
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+}
+
+#[test]
+fn unexpected_calls_are_recorded() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_count().returns(1);
+
+    greeter.greet("Jane");
+    greeter.count();
+    greeter.print(1_u8);
+
+    let matched_expected_calls: Vec<_> = mockiato::calls(&greeter)
+        .iter()
+        .map(|call| call.matched_expected_call().is_some())
+        .collect();
+
+    assert_eq!(vec![false, true, false], matched_expected_calls);
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for GreeterMock::greet were not satisified.")]
+fn expected_calls_are_still_verified() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    greeter.greet("Doe");
+}
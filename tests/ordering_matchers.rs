@@ -0,0 +1,80 @@
+use mockiato::mockable;
+use std::time::Duration;
+
+#[mockable]
+trait Scheduler {
+    fn schedule(&self, priority: u8, delay: Duration);
+}
+
+#[test]
+fn ordering_matchers_match_numeric_arguments() {
+    let mut scheduler = SchedulerMock::new();
+
+    scheduler
+        .expect_schedule(|arg| arg.gt(5), |arg| arg.any())
+        .returns(());
+    scheduler
+        .expect_schedule(|arg| arg.le(5), |arg| arg.any())
+        .returns(());
+
+    scheduler.schedule(6, Duration::from_secs(1));
+    scheduler.schedule(5, Duration::from_secs(1));
+}
+
+#[test]
+fn ordering_matchers_match_time_arguments() {
+    let mut scheduler = SchedulerMock::new();
+
+    scheduler
+        .expect_schedule(
+            |arg| arg.any(),
+            |arg| {
+                arg.all_of(vec![
+                    arg.ge(Duration::from_secs(1)),
+                    arg.lt(Duration::from_secs(2)),
+                ])
+            },
+        )
+        .returns(());
+
+    scheduler.schedule(1, Duration::from_millis(1500));
+}
+
+#[test]
+fn in_range_matches_arguments_in_range() {
+    let mut scheduler = SchedulerMock::new();
+
+    scheduler
+        .expect_schedule(|arg| arg.in_range(10..20), |arg| arg.any())
+        .returns(());
+    scheduler
+        .expect_schedule(|arg| arg.in_range(20..=30), |arg| arg.any())
+        .returns(());
+
+    scheduler.schedule(15, Duration::from_secs(1));
+    scheduler.schedule(30, Duration::from_secs(1));
+}
+
+#[test]
+#[should_panic(expected = "schedule(in ")]
+fn in_range_is_displayed_in_failure_message() {
+    let mut scheduler = SchedulerMock::new();
+
+    scheduler
+        .expect_schedule(|arg| arg.in_range(10..20), |arg| arg.any())
+        .returns(());
+
+    scheduler.schedule(20, Duration::from_secs(1));
+}
+
+#[test]
+#[should_panic(expected = "schedule(> ")]
+fn comparison_is_displayed_in_failure_message() {
+    let mut scheduler = SchedulerMock::new();
+
+    scheduler
+        .expect_schedule(|arg| arg.gt(5), |arg| arg.any())
+        .returns(());
+
+    scheduler.schedule(5, Duration::from_secs(1));
+}
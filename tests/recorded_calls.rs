@@ -0,0 +1,159 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn wave(&self);
+
+    fn print<T>(&self, value: T)
+    where
+        T: Debug + 'static;
+}
+
+#[mockable]
+trait Reader {
+    fn read(&self) -> u8;
+}
+
+#[mockable(supertrait_mocks(Reader = "ReaderMock"))]
+trait Connection: Reader {
+    fn send(&self, data: u8);
+}
+
+#[test]
+fn calls_are_recorded_in_order() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(2)
+        .returns(String::from("Hello"));
+    greeter.expect_wave().returns(());
+
+    greeter.greet("Jane");
+    greeter.wave();
+    greeter.greet("Doe");
+
+    let method_names: Vec<_> = mockiato::calls(&greeter)
+        .iter()
+        .map(|call| call.method_name().to_string())
+        .collect();
+
+    assert_eq!(
+        vec![
+            "GreeterMock::greet",
+            "GreeterMock::wave",
+            "GreeterMock::greet"
+        ],
+        method_names
+    );
+}
+
+#[test]
+fn calls_of_single_method_can_be_retrieved() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello"));
+    greeter.expect_wave().returns(());
+
+    greeter.wave();
+    greeter.greet("Jane");
+
+    let greet_calls = GreeterMock::calls_to_greet(&greeter);
+
+    assert_eq!(1, greet_calls.len());
+    assert_eq!("GreeterMock::greet", greet_calls[0].method_name());
+    assert!(greet_calls[0]
+        .matched_expected_call()
+        .unwrap()
+        .starts_with("greet("));
+}
+
+#[test]
+fn calls_of_generic_methods_are_recorded() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_print(|arg| arg.partial_eq(1_u8)).returns(());
+    greeter
+        .expect_print(|arg| arg.satisfies("is foo", |value: &String| value == "foo"))
+        .returns(());
+
+    greeter.print(String::from("foo"));
+    greeter.print(1_u8);
+
+    let method_names: Vec<_> = GreeterMock::calls_to_print(&greeter)
+        .iter()
+        .map(|call| call.method_name().to_string())
+        .collect();
+
+    assert_eq!(
+        vec![
+            "GreeterMock::print::<alloc::string::String>",
+            "GreeterMock::print::<u8>"
+        ],
+        method_names
+    );
+}
+
+#[test]
+fn calls_include_supertrait_mocks() {
+    let mut connection = ConnectionMock::new();
+
+    connection.reader_mock().expect_read().returns(1);
+    connection.expect_send(|arg| arg.partial_eq(1)).returns(());
+
+    connection.read();
+    connection.send(1);
+
+    let method_names: Vec<_> = mockiato::calls(&connection)
+        .iter()
+        .map(|call| call.method_name().to_string())
+        .collect();
+
+    assert_eq!(
+        vec!["ReaderMock::read", "ConnectionMock::send"],
+        method_names
+    );
+}
+
+#[test]
+#[should_panic(expected = "The following calls were made:\nGreeterMock::greet")]
+fn verification_error_shows_recorded_calls() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(2)
+        .returns(String::from("Hello"));
+
+    greeter.greet("Jane");
+}
+
+#[mockable]
+trait Counter {
+    fn calls(&self) -> usize;
+
+    fn greet(&self);
+
+    fn greet_calls(&self) -> usize;
+}
+
+#[test]
+fn recorded_calls_do_not_collide_with_trait_methods() {
+    let mut counter = CounterMock::new();
+
+    counter.expect_calls().returns(1);
+    counter.expect_greet().returns(());
+    counter.expect_greet_calls().returns(2);
+
+    assert_eq!(1, counter.calls());
+    counter.greet();
+    assert_eq!(2, counter.greet_calls());
+
+    assert_eq!(3, mockiato::calls(&counter).len());
+    assert_eq!(1, CounterMock::calls_to_greet(&counter).len());
+}
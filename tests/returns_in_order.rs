@@ -0,0 +1,75 @@
+use mockiato::mockable;
+
+#[derive(Debug, PartialEq)]
+struct Error;
+
+#[mockable]
+trait Connection {
+    fn connect(&self) -> Result<(), Error>;
+}
+
+#[test]
+fn values_are_returned_in_order() {
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_connect()
+        .times(3)
+        .returns_in_order(vec![Err(Error), Err(Error), Ok(())]);
+
+    assert_eq!(Err(Error), connection.connect());
+    assert_eq!(Err(Error), connection.connect());
+    assert_eq!(Ok(()), connection.connect());
+}
+
+#[test]
+fn times_can_be_specified_before_return_values() {
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_connect()
+        .times(1..=2)
+        .returns_in_order(vec![Err(Error), Ok(())]);
+
+    assert_eq!(Err(Error), connection.connect());
+}
+
+#[test]
+#[should_panic(
+    expected = "Return values can only be returned 2 times but call was expected exactly 3 times."
+)]
+fn setup_panics_when_more_calls_than_values_are_expected() {
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_connect()
+        .returns_in_order(vec![Err(Error), Ok(())])
+        .times(3);
+}
+
+#[test]
+#[should_panic(
+    expected = "Return values can only be returned 2 times but call was expected at least 3 times."
+)]
+fn setup_panics_when_more_calls_than_values_are_required() {
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_connect()
+        .times(3..)
+        .returns_in_order(vec![Err(Error), Ok(())]);
+}
+
+#[test]
+#[should_panic(
+    expected = "Return values can only be returned 2 times but call was expected at least 1 time. \
+                The number of expected calls needs an upper bound, e.g. `times(..=2)`."
+)]
+fn setup_panics_when_unlimited_calls_are_expected() {
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_connect()
+        .times(1..)
+        .returns_in_order(vec![Err(Error), Ok(())]);
+}
@@ -0,0 +1,72 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+}
+
+#[mockable]
+trait Calculator {
+    fn add(&self, a: u32, b: u32) -> u32;
+}
+
+#[derive(Debug, PartialEq)]
+struct Connection {
+    address: String,
+}
+
+#[mockable]
+trait ConnectionFactory {
+    fn connect(&self, address: String) -> Connection;
+}
+
+#[test]
+fn return_value_is_computed_from_arguments() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(2)
+        .returns_with(|arguments| format!("Hello {}", arguments.name));
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+    assert_eq!("Hello Doe", greeter.greet("Doe"));
+}
+
+#[test]
+fn return_value_can_be_computed_from_multiple_arguments() {
+    let mut calculator = CalculatorMock::new();
+
+    calculator
+        .expect_add(|arg| arg.any(), |arg| arg.any())
+        .times(..)
+        .returns_with(|arguments| arguments.a + arguments.b);
+
+    assert_eq!(3, calculator.add(1, 2));
+    assert_eq!(10, calculator.add(4, 6));
+}
+
+#[test]
+fn return_value_does_not_need_to_be_clone() {
+    let mut connection_factory = ConnectionFactoryMock::new();
+
+    connection_factory
+        .expect_connect(|arg| arg.any())
+        .times(2)
+        .returns_with(|arguments| Connection {
+            address: arguments.address,
+        });
+
+    assert_eq!(
+        Connection {
+            address: String::from("localhost")
+        },
+        connection_factory.connect(String::from("localhost"))
+    );
+    assert_eq!(
+        Connection {
+            address: String::from("example.com")
+        },
+        connection_factory.connect(String::from("example.com"))
+    );
+}
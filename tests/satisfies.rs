@@ -0,0 +1,46 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Counter {
+    fn add(&self, value: u32) -> u32;
+
+    fn label(&self, name: &str);
+}
+
+#[test]
+fn satisfies_matches_values_accepted_by_predicate() {
+    let mut counter = CounterMock::new();
+
+    counter
+        .expect_add(|arg| arg.satisfies("is even", |value: &u32| value % 2 == 0))
+        .returns(0);
+    counter
+        .expect_add(|arg| arg.satisfies("is odd", |value: &u32| value % 2 == 1))
+        .returns(1);
+
+    assert_eq!(0, counter.add(4));
+    assert_eq!(1, counter.add(7));
+}
+
+#[test]
+fn satisfies_matches_reference_arguments() {
+    let mut counter = CounterMock::new();
+
+    counter
+        .expect_label(|arg| arg.satisfies("starts with 'c'", |name: &&str| name.starts_with('c')))
+        .returns(());
+
+    counter.label("counter");
+}
+
+#[test]
+#[should_panic(expected = "add(is even)")]
+fn description_is_shown_in_failure_message() {
+    let mut counter = CounterMock::new();
+
+    counter
+        .expect_add(|arg| arg.satisfies("is even", |value: &u32| value % 2 == 0))
+        .returns(0);
+
+    counter.add(3);
+}
@@ -0,0 +1,112 @@
+use mockiato::{mockable, Sequence};
+
+#[mockable]
+trait File {
+    fn open(&self, path: &str);
+
+    fn write(&self, data: &str);
+
+    fn close(&self);
+}
+
+#[mockable]
+trait Logger {
+    fn log(&self, message: &str);
+}
+
+#[test]
+fn calls_to_different_methods_can_be_sequenced() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+
+    file.expect_open(|arg| arg.partial_eq("foo.txt"))
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_write(|arg| arg.any())
+        .times(2)
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_close().in_sequence(&sequence).returns(());
+
+    file.open("foo.txt");
+    file.write("foo");
+    file.write("bar");
+    file.close();
+}
+
+#[test]
+fn calls_to_different_mocks_can_be_sequenced() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+    let mut logger = LoggerMock::new();
+
+    logger
+        .expect_log(|arg| arg.partial_eq("opening file"))
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_open(|arg| arg.any())
+        .in_sequence(&sequence)
+        .returns(());
+    logger
+        .expect_log(|arg| arg.partial_eq("file opened"))
+        .in_sequence(&sequence)
+        .returns(());
+
+    logger.log("opening file");
+    file.open("foo.txt");
+    logger.log("file opened");
+}
+
+#[test]
+fn expected_calls_that_are_not_in_sequence_can_be_called_at_any_time() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+
+    file.expect_open(|arg| arg.any())
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_write(|arg| arg.any()).returns(());
+    file.expect_close().in_sequence(&sequence).returns(());
+
+    file.open("foo.txt");
+    file.close();
+    file.write("foo");
+}
+
+#[test]
+#[should_panic(
+    expected = "was made out of sequence.\nThe next expected call in the sequence was:\nlog("
+)]
+fn panics_if_call_is_made_before_previous_step() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+    let mut logger = LoggerMock::new();
+
+    logger
+        .expect_log(|arg| arg.partial_eq("opening file"))
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_open(|arg| arg.any())
+        .in_sequence(&sequence)
+        .returns(());
+
+    file.open("foo.txt");
+}
+
+#[test]
+#[should_panic(expected = "close() was made out of sequence.")]
+fn panics_if_step_is_called_after_sequence_moved_on() {
+    let sequence = Sequence::new();
+    let mut file = FileMock::new();
+
+    file.expect_close()
+        .times(..)
+        .in_sequence(&sequence)
+        .returns(());
+    file.expect_open(|arg| arg.any())
+        .in_sequence(&sequence)
+        .returns(());
+
+    file.open("foo.txt");
+    file.close();
+}
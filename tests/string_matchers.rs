@@ -0,0 +1,70 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Database {
+    fn execute(&self, sql: &str);
+
+    fn execute_owned(&self, sql: String);
+}
+
+#[test]
+fn string_matchers_match_str_arguments() {
+    let mut database = DatabaseMock::new();
+
+    database
+        .expect_execute(|arg| arg.starts_with("SELECT"))
+        .returns(());
+    database
+        .expect_execute(|arg| arg.ends_with("COMMIT"))
+        .returns(());
+    database
+        .expect_execute(|arg| arg.contains("INTO users"))
+        .returns(());
+    database
+        .expect_execute(|arg| arg.eq_ignore_ascii_case("rollback"))
+        .returns(());
+
+    database.execute("SELECT * FROM users");
+    database.execute("COMMIT");
+    database.execute("INSERT INTO users VALUES (1)");
+    database.execute("ROLLBACK");
+}
+
+#[test]
+fn string_matchers_match_string_arguments() {
+    let mut database = DatabaseMock::new();
+
+    database
+        .expect_execute_owned(|arg| arg.starts_with(String::from("SELECT")))
+        .returns(());
+
+    database.execute_owned(String::from("SELECT * FROM users"));
+}
+
+#[test]
+#[should_panic(expected = r#"execute(starts_with("SELECT"))"#)]
+fn string_matcher_is_displayed_in_failure_message() {
+    let mut database = DatabaseMock::new();
+
+    database
+        .expect_execute(|arg| arg.starts_with("SELECT"))
+        .returns(());
+
+    database.execute("DELETE FROM users");
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn matches_regex_matches_str_and_string_arguments() {
+    let mut database = DatabaseMock::new();
+
+    database
+        .expect_execute(|arg| arg.matches_regex(r"^SELECT .* FROM users$"))
+        .returns(());
+    database
+        .expect_execute_owned(|arg| arg.matches_regex(r"^DELETE"))
+        .returns(());
+
+    database.execute("SELECT id FROM users");
+    database.execute_owned(String::from("DELETE FROM users"));
+}
@@ -0,0 +1,133 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn print<T>(&self, value: T) -> String
+    where
+        T: Debug + 'static;
+}
+
+#[test]
+fn stub_is_used_for_calls_that_do_not_match_an_expected_call() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+    GreeterMock::stub_greet(&mut greeter).returns(String::from("Hello stranger"));
+
+    assert_eq!("Hello stranger", greeter.greet("Doe"));
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+    assert_eq!("Hello stranger", greeter.greet("Max"));
+}
+
+#[test]
+fn stub_is_used_for_calls_to_exhausted_expected_calls() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(1)
+        .returns(String::from("Hello Jane"));
+    GreeterMock::stub_greet(&mut greeter).returns(String::from("Hello stranger"));
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+    assert_eq!("Hello stranger", greeter.greet("Jane"));
+}
+
+#[test]
+fn stub_is_used_for_calls_to_exhausted_expected_calls_in_order() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_greet_calls_in_order();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(1)
+        .returns(String::from("Hello Jane"));
+    GreeterMock::stub_greet(&mut greeter).returns(String::from("Hello stranger"));
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+    assert_eq!("Hello stranger", greeter.greet("Jane"));
+}
+
+#[test]
+fn stub_does_not_need_to_be_called() {
+    let mut greeter = GreeterMock::new();
+
+    GreeterMock::stub_greet(&mut greeter).returns(String::from("Hello stranger"));
+}
+
+#[test]
+fn stub_is_used_for_calls_with_any_arguments() {
+    let mut greeter = GreeterMock::new();
+
+    GreeterMock::stub_greet(&mut greeter)
+        .returns_with(|arguments| format!("Hello {}", arguments.name));
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+    assert_eq!("Hello Doe", greeter.greet("Doe"));
+}
+
+#[test]
+fn stub_is_configured_per_instantiation_of_generic_method() {
+    let mut greeter = GreeterMock::new();
+
+    GreeterMock::stub_print::<u8>(&mut greeter).returns(String::from("u8"));
+    greeter
+        .expect_print(|arg| arg.partial_eq(1_u8))
+        .returns(String::from("one"));
+
+    assert_eq!("u8", greeter.print(2_u8));
+    assert_eq!("one", greeter.print(1_u8));
+}
+
+#[test]
+fn stub_is_kept_by_checkpoint() {
+    let mut greeter = GreeterMock::new();
+
+    GreeterMock::stub_greet(&mut greeter).returns(String::from("Hello stranger"));
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    assert_eq!("Hello Jane", greeter.greet("Jane"));
+    mockiato::checkpoint(&mut greeter);
+
+    assert_eq!("Hello stranger", greeter.greet("Jane"));
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for GreeterMock::greet were not satisified.")]
+fn expected_calls_are_verified_when_stub_is_set() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+    GreeterMock::stub_greet(&mut greeter).returns(String::from("Hello stranger"));
+
+    greeter.greet("Doe");
+}
+
+#[mockable]
+trait Fallback {
+    fn greet(&self) -> String;
+
+    fn stub_greet(&self) -> String;
+}
+
+#[test]
+fn stub_does_not_collide_with_trait_method_of_the_same_name() {
+    let mut fallback = FallbackMock::new();
+
+    FallbackMock::stub_greet(&mut fallback).returns(String::from("stubbed"));
+    fallback
+        .expect_stub_greet()
+        .returns(String::from("expected"));
+
+    assert_eq!("stubbed", fallback.greet());
+    assert_eq!("expected", fallback.stub_greet());
+}
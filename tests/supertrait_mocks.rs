@@ -0,0 +1,101 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Reader {
+    fn read(&self, length: usize) -> Vec<u8>;
+
+    fn close(&mut self);
+}
+
+#[mockable(constants(NAME = "writer"))]
+trait Writer {
+    const NAME: &'static str;
+
+    fn write(&self, data: &[u8]) -> usize;
+}
+
+#[mockable(supertrait_mocks(Reader = "ReaderMock"))]
+trait Connection: Reader {
+    fn send(&self, message: &str) -> bool;
+}
+
+#[mockable(supertrait_mocks(Reader = "ReaderMock", Writer = "WriterMock"))]
+trait Stream: Reader + Writer + std::fmt::Debug {
+    fn flush(&self);
+}
+
+#[test]
+fn supertrait_methods_are_forwarded_to_supertrait_mock() {
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_send(|arg| arg.partial_eq("ping"))
+        .returns(true);
+    connection
+        .reader_mock()
+        .expect_read(|arg| arg.partial_eq(4usize))
+        .returns(b"pong".to_vec());
+
+    assert!(connection.send("ping"));
+    assert_eq!(b"pong".to_vec(), connection.read(4));
+}
+
+#[test]
+fn mutable_supertrait_methods_are_forwarded() {
+    let mut connection = ConnectionMock::new();
+
+    connection.reader_mock().expect_close().returns(());
+
+    connection.close();
+}
+
+#[test]
+fn multiple_supertraits_can_be_mocked() {
+    let mut stream = StreamMock::new();
+
+    stream
+        .reader_mock()
+        .expect_read(|arg| arg.any())
+        .returns(Vec::new());
+    stream
+        .writer_mock()
+        .expect_write(|arg| arg.partial_eq(&[1u8, 2u8][..]))
+        .returns(2);
+    stream.expect_flush().returns(());
+
+    assert!(stream.read(10).is_empty());
+    assert_eq!(2, stream.write(&[1, 2]));
+    stream.flush();
+}
+
+#[test]
+fn associated_constants_are_forwarded() {
+    assert_eq!("writer", <StreamMock<'_> as Writer>::NAME);
+}
+
+#[test]
+fn mock_can_be_used_as_supertrait_object() {
+    fn read_all(reader: &dyn Reader) -> Vec<u8> {
+        reader.read(1024)
+    }
+
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .reader_mock()
+        .expect_read(|arg| arg.any())
+        .returns(vec![1, 2, 3]);
+
+    assert_eq!(vec![1, 2, 3], read_all(&connection));
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for ReaderMock::read were not satisified.")]
+fn expectations_of_supertrait_mocks_are_verified() {
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .reader_mock()
+        .expect_read(|arg| arg.any())
+        .returns(Vec::new());
+}
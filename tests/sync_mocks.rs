@@ -0,0 +1,95 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::thread;
+
+#[mockable(sync)]
+trait Service {
+    fn handle(&self, request: &str) -> String;
+}
+
+#[mockable(sync)]
+trait Storage {
+    fn store<T>(&self, value: T)
+    where
+        T: Debug + 'static;
+}
+
+#[mockable(sync)]
+trait Repository<T> {
+    fn find(&self, id: u32) -> Option<T>;
+}
+
+fn assert_send_and_sync<T>(_: &T)
+where
+    T: Send + Sync,
+{
+}
+
+#[test]
+fn sync_mock_is_send_and_sync() {
+    let service = ServiceMock::new();
+    let storage = StorageMock::new();
+    let repository = RepositoryMock::<String>::new();
+
+    assert_send_and_sync(&service);
+    assert_send_and_sync(&storage);
+    assert_send_and_sync(&repository);
+}
+
+#[test]
+fn sync_mock_can_be_used_by_other_threads() {
+    let mut service = ServiceMock::new();
+
+    service
+        .expect_handle(|arg| arg.partial_eq("ping"))
+        .times(4)
+        .returns(String::from("pong"));
+
+    let service: Arc<dyn Service + Send + Sync> = Arc::new(service);
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let service = service.clone();
+            thread::spawn(move || service.handle("ping"))
+        })
+        .collect();
+
+    for worker in workers {
+        assert_eq!("pong", worker.join().unwrap());
+    }
+}
+
+#[test]
+fn generic_methods_of_sync_mock_can_be_called_from_other_threads() {
+    let mut storage = StorageMock::new();
+
+    storage
+        .expect_store(|arg| arg.partial_eq(42_u32))
+        .returns(());
+
+    let storage = Arc::new(storage);
+    let worker = {
+        let storage = storage.clone();
+        thread::spawn(move || storage.store(42_u32))
+    };
+
+    worker.join().unwrap();
+}
+
+#[test]
+fn values_returned_once_can_be_returned_on_another_thread() {
+    let mut repository = RepositoryMock::new();
+
+    repository
+        .expect_find(|arg| arg.partial_eq(1))
+        .returns_once(Some(String::from("first")));
+
+    let repository = Arc::new(repository);
+    let worker = {
+        let repository = repository.clone();
+        thread::spawn(move || repository.find(1))
+    };
+
+    assert_eq!(Some(String::from("first")), worker.join().unwrap());
+}
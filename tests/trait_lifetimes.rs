@@ -0,0 +1,73 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token<'input> {
+    text: &'input str,
+}
+
+#[mockable]
+trait Parser<'input> {
+    fn parse(&self, input: &'input str) -> Token<'input>;
+
+    fn peek(&self) -> Option<&'input str>;
+}
+
+/// The expectations of generic methods are `'static`,
+/// which is why the trait's lifetimes need to be `'static` as well.
+#[mockable]
+trait Converter<'input> {
+    fn parse_as<T: 'static>(&self, input: &'input str) -> T;
+}
+
+#[mockable]
+trait Lookup<'a, K>
+where
+    K: Debug + 'a,
+{
+    fn lookup(&self, key: K) -> &'a str;
+}
+
+#[test]
+fn trait_with_lifetime_can_be_mocked() {
+    let input = String::from("foo bar");
+    let mut parser = ParserMock::new();
+
+    parser
+        .expect_parse(|arg| arg.partial_eq("foo bar"))
+        .returns(Token { text: &input[..3] });
+
+    assert_eq!(Token { text: "foo" }, parser.parse(&input));
+}
+
+#[test]
+fn trait_lifetime_can_be_used_in_return_value() {
+    let input = String::from("foo");
+    let mut parser = ParserMock::new();
+
+    parser.expect_peek().returns(Some(&input[..]));
+
+    assert_eq!(Some("foo"), parser.peek());
+}
+
+#[test]
+fn trait_with_lifetime_and_generic_type_can_be_mocked() {
+    let mut lookup = LookupMock::new();
+
+    lookup
+        .expect_lookup(|arg| arg.partial_eq(42))
+        .returns("answer");
+
+    assert_eq!("answer", lookup.lookup(42));
+}
+
+#[test]
+fn trait_with_lifetime_and_generic_method_can_be_mocked() {
+    let mut converter = ConverterMock::new();
+
+    converter
+        .expect_parse_as(|arg| arg.partial_eq("42"))
+        .returns(42u32);
+
+    assert_eq!(42u32, converter.parse_as("42"));
+}
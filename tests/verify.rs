@@ -0,0 +1,123 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+
+    fn wave(&self);
+}
+
+#[mockable]
+trait Reader {
+    fn read(&self) -> u8;
+}
+
+#[mockable(supertrait_mocks(Reader = "ReaderMock"))]
+trait Connection: Reader {
+    fn send(&self, data: u8);
+}
+
+#[test]
+fn verify_is_ok_if_all_expected_calls_were_made() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello Jane"));
+
+    greeter.greet("Jane");
+
+    assert!(mockiato::verify(greeter).is_ok());
+}
+
+#[test]
+fn verify_reports_unsatisfied_expectations_of_all_methods() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .times(2)
+        .returns(String::from("Hello"));
+    greeter.expect_wave().returns(());
+
+    greeter.greet("Jane");
+
+    let report = mockiato::verify(greeter).unwrap_err();
+    let mut method_names: Vec<_> = report
+        .unsatisfied_expectations()
+        .iter()
+        .map(|unsatisfied_expectation| unsatisfied_expectation.method_name())
+        .collect();
+    method_names.sort();
+
+    assert_eq!(
+        vec!["GreeterMock::greet", "GreeterMock::wave"],
+        method_names
+    );
+}
+
+#[test]
+fn verify_reports_number_of_calls() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_wave().times(3..).returns(());
+
+    greeter.wave();
+
+    let report = mockiato::verify(greeter).unwrap_err();
+    let unsatisfied_expectation = &report.unsatisfied_expectations()[0];
+
+    assert_eq!(1, unsatisfied_expectation.number_of_calls());
+    assert_eq!(
+        "at least 3 times",
+        unsatisfied_expectation.expected_calls().to_string()
+    );
+}
+
+#[test]
+fn verify_includes_supertrait_mocks() {
+    let mut connection = ConnectionMock::new();
+
+    connection.reader_mock().expect_read().returns(1);
+
+    let report = mockiato::verify(connection).unwrap_err();
+
+    assert_eq!(
+        "ReaderMock::read",
+        report.unsatisfied_expectations()[0].method_name()
+    );
+}
+
+#[test]
+fn verified_mock_is_not_verified_again_when_dropped() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_wave().returns(());
+
+    assert!(mockiato::verify(greeter).is_err());
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for GreeterMock::wave were not satisified.")]
+fn verifying_clone_does_not_disable_verification_when_dropped() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_wave().returns(());
+
+    assert!(mockiato::verify(greeter.clone()).is_err());
+}
+
+#[mockable]
+trait Signature {
+    fn verify(&self, data: &[u8]) -> bool;
+}
+
+#[test]
+fn verify_does_not_collide_with_trait_method_of_the_same_name() {
+    let mut signature = SignatureMock::new();
+
+    signature.expect_verify(|arg| arg.any()).returns(true);
+    assert!(signature.verify(&[1, 2, 3]));
+
+    assert!(mockiato::verify(signature).is_ok());
+}